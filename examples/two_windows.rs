@@ -0,0 +1,64 @@
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+#![allow(clippy::single_match)]
+
+//! A demo of having more than one window open at once.
+//!
+//! Each window gets its own renderer, and the window events are sent to the
+//! right window by checking the `window_id` of the event. Closing a window
+//! just hides it, and the program ends once both are closed.
+
+use beryllium::*;
+
+fn main() -> Result<(), String> {
+  let sdl = unsafe { beryllium::init()? };
+
+  let left = sdl.create_window("Left", 100, 100, 400, 300, WindowFlags::default())?;
+  let right = sdl.create_window("Right", 550, 100, 400, 300, WindowFlags::default())?;
+
+  // Safety Rules: Each renderer only gets used with its own window, and we
+  // don't make any textures, so there's nothing to mix up.
+  let flags = RendererFlags::default().with_accelerated(true);
+  let left_renderer = unsafe { left.create_renderer(None, flags)? };
+  let right_renderer = unsafe { right.create_renderer(None, flags)? };
+
+  let windows = [
+    (left.id()?, &left, &left_renderer, Color::RED),
+    (right.id()?, &right, &right_renderer, Color::BLUE),
+  ];
+  let mut open = [true, true];
+
+  'game_loop: loop {
+    while let Some(event) = sdl.poll_event() {
+      match event {
+        Event::Quit { .. } => break 'game_loop,
+        Event::Window { event, .. } => {
+          let index = windows.iter().position(|w| w.0 == event.window_id());
+          match (index, event) {
+            (Some(i), WindowEvent::Close { .. }) => {
+              println!("Closing window {}", windows[i].0);
+              windows[i].1.hide();
+              open[i] = false;
+            }
+            (Some(i), WindowEvent::FocusGained { .. }) => {
+              println!("Window {} has focus", windows[i].0);
+            }
+            _ => (),
+          }
+        }
+        _ => (),
+      }
+    }
+    if !open.iter().any(|&o| o) {
+      break 'game_loop;
+    }
+    for (&(_, _, renderer, color), &is_open) in windows.iter().zip(open.iter()) {
+      if is_open {
+        renderer.set_draw_color(color)?;
+        renderer.clear()?;
+        renderer.present();
+      }
+    }
+  }
+
+  Ok(())
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+/// How colors are combined when one image is drawn onto another.
+///
+/// `src` is the color being drawn, `dst` is the color already there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(windows, repr(i32))]
+#[cfg_attr(not(windows), repr(u32))]
+pub enum BlendMode {
+  /// No blending: `dstRGBA = srcRGBA`
+  None = SDL_BLENDMODE_NONE,
+  /// Alpha blending:
+  /// * `dstRGB = (srcRGB * srcA) + (dstRGB * (1-srcA))`
+  /// * `dstA = srcA + (dstA * (1-srcA))`
+  Blend = SDL_BLENDMODE_BLEND,
+  /// Additive blending:
+  /// * `dstRGB = (srcRGB * srcA) + dstRGB`
+  /// * `dstA = dstA`
+  Add = SDL_BLENDMODE_ADD,
+  /// Color modulate:
+  /// * `dstRGB = srcRGB * dstRGB`
+  /// * `dstA = dstA`
+  Mod = SDL_BLENDMODE_MOD,
+  /// Any blend mode that isn't one of the standard ones.
+  Invalid = SDL_BLENDMODE_INVALID,
+}
+impl From<SDL_BlendMode::Type> for BlendMode {
+  fn from(mode: SDL_BlendMode::Type) -> Self {
+    match mode {
+      SDL_BLENDMODE_NONE => BlendMode::None,
+      SDL_BLENDMODE_BLEND => BlendMode::Blend,
+      SDL_BLENDMODE_ADD => BlendMode::Add,
+      SDL_BLENDMODE_MOD => BlendMode::Mod,
+      _ => BlendMode::Invalid,
+    }
+  }
+}
@@ -71,7 +71,45 @@ impl<'sdl> Controller<'sdl> {
     }
   }
 
+  /// Gets the battery level of the controller.
+  ///
+  /// Gives `Unknown` if the level can't be found out.
+  pub fn power_level(&self) -> JoystickPowerLevel {
+    let joystick_ptr: *mut SDL_Joystick = unsafe { SDL_GameControllerGetJoystick(self.ptr) };
+    if joystick_ptr.is_null() {
+      JoystickPowerLevel::Unknown
+    } else {
+      JoystickPowerLevel::from(unsafe { SDL_JoystickCurrentPowerLevel(joystick_ptr) })
+    }
+  }
+
+  /// The GUID of this controller, as a string.
+  ///
+  /// Unlike the [instance_id](Controller::instance_id), which is different
+  /// every time the device is plugged in, the GUID is the same for every
+  /// device of a given model. That makes it the thing to key saved bindings
+  /// on. An all-zero GUID means that it couldn't be found.
+  pub fn guid(&self) -> String {
+    let joystick_ptr: *mut SDL_Joystick = unsafe { SDL_GameControllerGetJoystick(self.ptr) };
+    if joystick_ptr.is_null() {
+      guid_to_string([0; 16])
+    } else {
+      guid_to_string(unsafe { SDL_JoystickGetGUID(joystick_ptr) }.data)
+    }
+  }
+
+  /// Attempts to get the instance id of this controller.
+  ///
+  /// This is the id that events use to say which controller they're about,
+  /// and it's what you pass to
+  /// [controller_from_instance_id](SDLToken::controller_from_instance_id).
+  pub fn instance_id(&self) -> Result<JoystickID, String> {
+    self.joystick_id()
+  }
+
   /// Attempts to get the joystick ID of this controller.
+  ///
+  /// This is the same as the [instance_id](Controller::instance_id).
   pub fn joystick_id(&self) -> Result<JoystickID, String> {
     let joystick_ptr: *mut SDL_Joystick = unsafe { SDL_GameControllerGetJoystick(self.ptr) };
     if joystick_ptr.is_null() {
@@ -87,6 +125,146 @@ impl<'sdl> Controller<'sdl> {
   }
 }
 
+/// Handle to a joystick.
+///
+/// This is the lower level way to use a device, you usually want a
+/// [Controller] if the device supports it.
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Joystick<'sdl> {
+  pub(crate) ptr: *mut SDL_Joystick,
+  pub(crate) _marker: PhantomData<&'sdl SDLToken>,
+}
+impl<'sdl> Drop for Joystick<'sdl> {
+  fn drop(&mut self) {
+    unsafe { SDL_JoystickClose(self.ptr) }
+  }
+}
+impl<'sdl> Joystick<'sdl> {
+  /// Gets the battery level of the joystick.
+  pub fn power_level(&self) -> JoystickPowerLevel {
+    JoystickPowerLevel::from(unsafe { SDL_JoystickCurrentPowerLevel(self.ptr) })
+  }
+
+  /// The implementation defined name for this joystick.
+  pub fn name(&self) -> Option<String> {
+    let ptr = unsafe { SDL_JoystickName(self.ptr) };
+    if ptr.is_null() {
+      None
+    } else {
+      unsafe { Some(gather_string(ptr)) }
+    }
+  }
+
+  /// The GUID of this joystick, as a string.
+  ///
+  /// See [Controller::guid].
+  pub fn guid(&self) -> String {
+    guid_to_string(unsafe { SDL_JoystickGetGUID(self.ptr) }.data)
+  }
+
+  /// Attempts to get the joystick ID of this joystick.
+  pub fn joystick_id(&self) -> Result<JoystickID, String> {
+    let out = unsafe { SDL_JoystickInstanceID(self.ptr) };
+    if out < 0 {
+      Err(get_error())
+    } else {
+      Ok(JoystickID(out))
+    }
+  }
+}
+
+/// Formats a joystick GUID as a string.
+///
+/// This is 32 lowercase hex digits, the same as `SDL_JoystickGetGUIDString`
+/// gives (and the same as the first field of a controller mapping string).
+pub fn guid_to_string(guid: [u8; 16]) -> String {
+  guid.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Parses a joystick GUID string, as made by [guid_to_string].
+///
+/// Like `SDL_JoystickGetGUIDFromString`, this never fails: any bad hex digits
+/// are read as 0, and a short string leaves the remaining bytes as 0.
+pub fn guid_from_string(s: &str) -> [u8; 16] {
+  fn nibble(c: u8) -> u8 {
+    (c as char).to_digit(16).unwrap_or(0) as u8
+  }
+  let mut out = [0_u8; 16];
+  for (byte, pair) in out.iter_mut().zip(s.as_bytes().chunks_exact(2)) {
+    *byte = (nibble(pair[0]) << 4) | nibble(pair[1]);
+  }
+  out
+}
+
+/// Turns a raw axis value into the `-1.0 ..= 1.0` range, with a deadzone.
+///
+/// Values within `deadzone` of the center give 0.0, and the rest of the range
+/// is rescaled so that the output still smoothly goes all the way to 1.0
+/// instead of jumping up from 0.0 at the edge of the deadzone.
+///
+/// This is for a single axis, such as a trigger. For sticks you usually want
+/// [apply_stick_deadzone].
+pub fn apply_deadzone(value: i16, deadzone: i16) -> f32 {
+  let deadzone = f32::from(deadzone.max(0));
+  let magnitude = f32::from(value).abs();
+  if magnitude <= deadzone {
+    0.0
+  } else {
+    let scaled = ((magnitude - deadzone) / (f32::from(i16::MAX) - deadzone)).min(1.0);
+    scaled.copysign(f32::from(value))
+  }
+}
+
+/// Turns a raw stick position into the `-1.0 ..= 1.0` range, with a round
+/// deadzone.
+///
+/// Rather than a deadzone on each axis on its own (which gives a "plus" shape
+/// that's sticky along the axes), this uses the distance from the center. The
+/// direction of the stick is kept, and the distance is rescaled like with
+/// [apply_deadzone].
+pub fn apply_stick_deadzone(x: i16, y: i16, deadzone: i16) -> (f32, f32) {
+  let deadzone = f32::from(deadzone.max(0));
+  let (x, y) = (f32::from(x), f32::from(y));
+  let magnitude = (x * x + y * y).sqrt();
+  if magnitude <= deadzone {
+    (0.0, 0.0)
+  } else {
+    let scaled = ((magnitude - deadzone) / (f32::from(i16::MAX) - deadzone)).min(1.0);
+    (x / magnitude * scaled, y / magnitude * scaled)
+  }
+}
+
+/// The battery level of a [Joystick] or [Controller].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum JoystickPowerLevel {
+  /// The level isn't known (or there was an error).
+  Unknown = SDL_JOYSTICK_POWER_UNKNOWN,
+  /// 5% or less.
+  Empty = SDL_JOYSTICK_POWER_EMPTY,
+  /// 20% or less.
+  Low = SDL_JOYSTICK_POWER_LOW,
+  /// 70% or less.
+  Medium = SDL_JOYSTICK_POWER_MEDIUM,
+  /// 100% or less.
+  Full = SDL_JOYSTICK_POWER_FULL,
+  /// Plugged in, with no battery to worry about.
+  Wired = SDL_JOYSTICK_POWER_WIRED,
+}
+impl From<fermium::SDL_JoystickPowerLevel::Type> for JoystickPowerLevel {
+  fn from(level: fermium::SDL_JoystickPowerLevel::Type) -> Self {
+    match level {
+      SDL_JOYSTICK_POWER_EMPTY => JoystickPowerLevel::Empty,
+      SDL_JOYSTICK_POWER_LOW => JoystickPowerLevel::Low,
+      SDL_JOYSTICK_POWER_MEDIUM => JoystickPowerLevel::Medium,
+      SDL_JOYSTICK_POWER_FULL => JoystickPowerLevel::Full,
+      SDL_JOYSTICK_POWER_WIRED => JoystickPowerLevel::Wired,
+      _ => JoystickPowerLevel::Unknown,
+    }
+  }
+}
+
 /// The types of axises that a Controller has.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(i32)]
@@ -0,0 +1,172 @@
+use super::*;
+
+/// The basic drawing operations, as something other code can be generic over.
+///
+/// [Renderer] implements this by really drawing, and [Recorder] implements
+/// it by writing down each call as a [DrawCommand]. If your UI or layout code
+/// draws through a `&dyn DrawTarget` (or a `T: DrawTarget`) then it can be
+/// tested by checking the recorded commands, without a window or a GPU.
+///
+/// The methods are the same as the [Renderer] methods of the same names.
+pub trait DrawTarget {
+  /// See [Renderer::clear].
+  fn clear(&self) -> Result<(), String>;
+  /// See [Renderer::set_draw_color].
+  fn set_draw_color(&self, color: Color) -> Result<(), String>;
+  /// See [Renderer::draw_line].
+  fn draw_line(&self, x1: i32, y1: i32, x2: i32, y2: i32) -> Result<(), String>;
+  /// See [Renderer::draw_lines].
+  fn draw_lines(&self, points: &[Point]) -> Result<(), String>;
+  /// See [Renderer::draw_points].
+  fn draw_points(&self, points: &[Point]) -> Result<(), String>;
+  /// See [Renderer::fill_rects].
+  fn fill_rects(&self, rects: &[Rect]) -> Result<(), String>;
+  /// See [Renderer::copy].
+  fn copy(&self, t: &Texture, src: Option<Rect>, dst: Option<Rect>) -> Result<(), String>;
+
+  /// Fills a single rectangle.
+  fn fill_rect(&self, rect: Rect) -> Result<(), String> {
+    self.fill_rects(&[rect])
+  }
+}
+
+impl<'sdl, 'win> DrawTarget for Renderer<'sdl, 'win> {
+  fn clear(&self) -> Result<(), String> {
+    Renderer::clear(self)
+  }
+  fn set_draw_color(&self, color: Color) -> Result<(), String> {
+    Renderer::set_draw_color(self, color)
+  }
+  fn draw_line(&self, x1: i32, y1: i32, x2: i32, y2: i32) -> Result<(), String> {
+    Renderer::draw_line(self, x1, y1, x2, y2)
+  }
+  fn draw_lines(&self, points: &[Point]) -> Result<(), String> {
+    Renderer::draw_lines(self, points)
+  }
+  fn draw_points(&self, points: &[Point]) -> Result<(), String> {
+    Renderer::draw_points(self, points)
+  }
+  fn fill_rects(&self, rects: &[Rect]) -> Result<(), String> {
+    Renderer::fill_rects(self, rects)
+  }
+  fn copy(&self, t: &Texture, src: Option<Rect>, dst: Option<Rect>) -> Result<(), String> {
+    Renderer::copy(self, t, src, dst)
+  }
+}
+
+/// A single recorded call to a [DrawTarget].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum DrawCommand {
+  Clear,
+  SetDrawColor(Color),
+  DrawLine {
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+  },
+  DrawLines(Vec<Point>),
+  DrawPoints(Vec<Point>),
+  FillRects(Vec<Rect>),
+  /// The texture is only kept as an identity, it's not owned.
+  Copy {
+    texture: RawTexturePtr,
+    src: Option<Rect>,
+    dst: Option<Rect>,
+  },
+}
+
+/// A [DrawTarget] that records every call instead of drawing.
+///
+/// None of the calls fail, and none of them touch SDL2, so this works in
+/// tests that never call [init].
+///
+/// ```rust
+/// use beryllium::*;
+/// let recorder = Recorder::new();
+/// recorder.set_draw_color(Color::RED).unwrap();
+/// let rect = Rect { x: 0, y: 0, w: 10, h: 10 };
+/// recorder.fill_rect(rect).unwrap();
+/// assert_eq!(
+///   recorder.take_commands(),
+///   vec![
+///     DrawCommand::SetDrawColor(Color::RED),
+///     DrawCommand::FillRects(vec![rect]),
+///   ]
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct Recorder {
+  commands: RefCell<Vec<DrawCommand>>,
+}
+impl Recorder {
+  /// Makes a recorder with no commands.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// A copy of the commands recorded so far.
+  pub fn commands(&self) -> Vec<DrawCommand> {
+    self.commands.borrow().clone()
+  }
+
+  /// Takes the commands recorded so far, leaving the recorder empty.
+  pub fn take_commands(&self) -> Vec<DrawCommand> {
+    core::mem::take(&mut *self.commands.borrow_mut())
+  }
+
+  /// Does each command again on another target, in order.
+  ///
+  /// A recorded `Copy` only remembers which texture it used, not the texture
+  /// itself, so it can't be replayed. Those give an error (after all of the
+  /// commands before them have been done).
+  pub fn replay(commands: &[DrawCommand], target: &dyn DrawTarget) -> Result<(), String> {
+    for command in commands {
+      match command {
+        DrawCommand::Clear => target.clear()?,
+        DrawCommand::SetDrawColor(color) => target.set_draw_color(*color)?,
+        DrawCommand::DrawLine { x1, y1, x2, y2 } => target.draw_line(*x1, *y1, *x2, *y2)?,
+        DrawCommand::DrawLines(points) => target.draw_lines(points)?,
+        DrawCommand::DrawPoints(points) => target.draw_points(points)?,
+        DrawCommand::FillRects(rects) => target.fill_rects(rects)?,
+        DrawCommand::Copy { .. } => {
+          return Err("beryllium error: a recorded copy can't be replayed.".to_string())
+        }
+      }
+    }
+    Ok(())
+  }
+
+  fn push(&self, command: DrawCommand) -> Result<(), String> {
+    self.commands.borrow_mut().push(command);
+    Ok(())
+  }
+}
+impl DrawTarget for Recorder {
+  fn clear(&self) -> Result<(), String> {
+    self.push(DrawCommand::Clear)
+  }
+  fn set_draw_color(&self, color: Color) -> Result<(), String> {
+    self.push(DrawCommand::SetDrawColor(color))
+  }
+  fn draw_line(&self, x1: i32, y1: i32, x2: i32, y2: i32) -> Result<(), String> {
+    self.push(DrawCommand::DrawLine { x1, y1, x2, y2 })
+  }
+  fn draw_lines(&self, points: &[Point]) -> Result<(), String> {
+    self.push(DrawCommand::DrawLines(points.to_vec()))
+  }
+  fn draw_points(&self, points: &[Point]) -> Result<(), String> {
+    self.push(DrawCommand::DrawPoints(points.to_vec()))
+  }
+  fn fill_rects(&self, rects: &[Rect]) -> Result<(), String> {
+    self.push(DrawCommand::FillRects(rects.to_vec()))
+  }
+  fn copy(&self, t: &Texture, src: Option<Rect>, dst: Option<Rect>) -> Result<(), String> {
+    self.push(DrawCommand::Copy {
+      texture: RawTexturePtr(t.ptr),
+      src,
+      dst,
+    })
+  }
+}
@@ -1,7 +1,14 @@
 use super::*;
 
 /// The various events that can happen.
-#[derive(Debug, Clone, Copy)]
+///
+/// Each variant keeps the `timestamp` that SDL2 gave the event, in
+/// milliseconds since SDL2 was initialized (the same clock as
+/// `SDL_GetTicks`). It's a field on every variant rather than a wrapper
+/// struct around the enum so that you can still match on events directly.
+/// Use [timestamp](Event::timestamp) when you want it without a match, such
+/// as for timing how long a button was held.
+#[derive(Debug, Clone)]
 pub enum Event {
   /// A controller axis is in a new position.
   ControllerAxis {
@@ -32,10 +39,14 @@ pub enum Event {
     pressed: bool,
   },
   /// A controller was added.
+  ///
+  /// Here the `joystick_id` is a _device index_, which is what you pass to
+  /// [open_controller](SDLToken::open_controller) to start using the new
+  /// controller.
   ControllerDeviceAdded {
     /// When the event happened
     timestamp: u32,
-    /// The controller
+    /// The device index of the new controller
     joystick_id: JoystickID,
   },
   /// The button mapping layout for a controller has changed.
@@ -46,10 +57,38 @@ pub enum Event {
     joystick_id: JoystickID,
   },
   /// A controller was removed.
+  ///
+  /// Here the `joystick_id` is an _instance id_, the same value that
+  /// [Controller::joystick_id](Controller::joystick_id) gives. You should drop
+  /// the matching `Controller`.
   ControllerDeviceRemoved {
     /// When the event happened
     timestamp: u32,
-    /// The controller
+    /// The instance id of the removed controller
+    joystick_id: JoystickID,
+  },
+  /// A joystick was added.
+  ///
+  /// Here the `joystick_id` is a _device index_, which is what you pass to
+  /// [open_joystick](SDLToken::open_joystick) to start using the new joystick.
+  ///
+  /// Controllers also count as joysticks, so you'll get one of these along
+  /// with each `ControllerDeviceAdded`.
+  JoyDeviceAdded {
+    /// When the event happened
+    timestamp: u32,
+    /// The device index of the new joystick
+    joystick_id: JoystickID,
+  },
+  /// A joystick was removed.
+  ///
+  /// Here the `joystick_id` is an _instance id_, the same value that
+  /// [Joystick::joystick_id](Joystick::joystick_id) gives. You should drop
+  /// the matching `Joystick`.
+  JoyDeviceRemoved {
+    /// When the event happened
+    timestamp: u32,
+    /// The instance id of the removed joystick
     joystick_id: JoystickID,
   },
   /// Keyboard button event information (a press or release).
@@ -60,8 +99,8 @@ pub enum Event {
     window_id: u32,
     /// If the key was pressed or released by this event.
     is_key_down: bool,
-    /// If this is a "key repeat" event (usually 0 or 1, though possibly more).
-    repeat_count: u8,
+    /// If this is a "key repeat" event from the key being held down.
+    repeat: bool,
     /// The information about the key being pressed or released.
     key_info: KeyInfo,
   },
@@ -78,30 +117,15 @@ pub enum Event {
     /// If the button is now pressed or released
     is_pressed: bool,
     /// 1 for single-click, 2 for double-click, etc
+    ///
+    /// SDL2 already does the timing for this (using the system's double-click
+    /// time), so a double-click is just `clicks == 2`.
     clicks: u8,
     /// X, relative to the window
     x: i32,
     /// Y, relative to the window
     y: i32,
   },
-  /// The window has gained mouse focus.
-  ///
-  /// Inverse of [`Event::MouseLeftWindow`].
-  MouseEnteredWindow {
-    /// When the event happened
-    timestamp: u32,
-    /// The window which gained mouse focus.
-    window_id: u32,
-  },
-  /// The window has lost mouse focus.
-  ///
-  /// Inverse of [`Event::MouseEnteredWindow`].
-  MouseLeftWindow {
-    /// When the event happened
-    timestamp: u32,
-    /// The window which lost mouse focus.
-    window_id: u32,
-  },
   /// Event for any time the user moves the mouse within a window, or if
   /// `warp_mouse_in_window` is called.
   MouseMotion {
@@ -143,131 +167,61 @@ pub enum Event {
     /// Time, in milliseconds, since SDL2 was initialized.
     timestamp: u32,
   },
-  /// The window manager requests that the window be closed.
-  WindowClosed {
-    /// When the event happened
-    timestamp: u32,
-    /// The window which wants to be closed.
-    window_id: u32,
-  },
-  /// The window has gained keyboard focus.
-  ///
-  /// Inverse of [`Event::WindowLostFocus`].
-  WindowGainedFocus {
-    /// When the event happened
-    timestamp: u32,
-    /// The window which gained keyboard focus.
-    window_id: u32,
-  },
-  /// The window has lost keyboard focus.
+  /// The text input composition (from an IME) has changed.
   ///
-  /// Inverse of [`Event::WindowGainedFocus`].
-  WindowLostFocus {
-    /// When the event happened
-    timestamp: u32,
-    /// The window which lost keyboard focus.
-    window_id: u32,
-  },
-  /// The window has been moved.
-  WindowMoved {
-    /// When the event happened
-    timestamp: u32,
-    /// The window which experienced a size change.
-    window_id: u32,
-    /// The new x position of the window.
-    x: i32,
-    /// The new y position of the window.
-    y: i32,
-  },
-  /// The size of the window has been changed externally.
-  ///
-  /// This event is always preceded by a [`Event::WindowSizeChanged`], however
-  /// the inverse is not always true.
+  /// This is the in-progress text that the user hasn't committed yet, which
+  /// you should draw at the text cursor. The selection within the composition
+  /// is `length` characters starting from `start`.
   ///
-  /// The difference between these two events is that `Event::WindowResized` is
-  /// only generated if the resize is triggered externally (by a user, their
-  /// window manager, etc), and *not* by calls to `beryllium` functions which
-  /// may change the size of a window, such as `Window::set_size`,
-  /// `Window::set_display_mode`, whereas [`Event::WindowSizeChanged`] is called
-  /// whenever the size of the window is changed, regardless of the cause.
-  WindowResized {
+  /// The composition text is limited to 32 bytes (including the null
+  /// terminator) by SDL2, so longer compositions are cut short.
+  TextEditing {
     /// When the event happened
     timestamp: u32,
-    /// The window which experienced a size change.
+    /// The window with keyboard focus, if any
     window_id: u32,
-    /// The new width of the window.
-    width: i32,
-    /// The new height of the window.
-    height: i32,
+    /// The composition text
+    text: String,
+    /// The start of the selection within the composition
+    start: i32,
+    /// The length of the selection within the composition
+    length: i32,
   },
-  /// Something has changed the size of the window.
-  ///
-  /// If (and only if) this was done by an external action (the user or window
-  /// manager changing the size of the window, for example), this event will be
-  /// followed by an [`Event::WindowResized`].
-  WindowSizeChanged {
+  /// Something happened to a window.
+  Window {
     /// When the event happened
     timestamp: u32,
-    /// The window which experienced a size change.
-    window_id: u32,
-    /// The new width of the window.
-    width: i32,
-    /// The new height of the window.
-    height: i32,
-  },
-  /// The window has been hidden.
-  ///
-  /// Inverse of [`Event::WindowShown`].
-  WindowHidden {
-    /// When the event happened
-    timestamp: u32,
-    /// The window which gained or lost mouse focus.
-    window_id: u32,
-  },
-  /// The window has been minimized.
-  WindowMinimized {
-    /// When the event happened
-    timestamp: u32,
-    /// The window which has been minimized.
-    window_id: u32,
-  },
-  /// The window needs repainting for one reason or another.
-  ///
-  /// This maps to `SDL_WINDOWEVENT_EXPOSED`, but has been renamed in order to
-  /// make it more clear what the event indicates.
-  WindowNeedsRepaint {
-    /// When the event happened
-    timestamp: u32,
-    /// The window which gained or lost mouse focus.
-    window_id: u32,
-  },
-  /// The window has been shown.
-  ///
-  /// Inverse of [`Event::WindowHidden`].
-  WindowShown {
-    /// When the event happened
-    timestamp: u32,
-    /// The window which gained or lost mouse focus.
-    window_id: u32,
-  },
-  /// The window has been maximized.
-  WindowMaximized {
-    /// When the event happened
-    timestamp: u32,
-    /// The window which has been maximized.
-    window_id: u32,
-  },
-  /// The window has been restored to normal size and position.
-  WindowRestored {
-    /// When the event happened
-    timestamp: u32,
-    /// The window which has been restored.
-    window_id: u32,
+    /// What happened
+    event: WindowEvent,
   },
   /// It's always possible that we'll load some future version which will have
   /// event variants we don't understand, which we have to just ignore.
   UnknownEventType,
 }
+impl Event {
+  /// When the event happened, in milliseconds since SDL2 was initialized.
+  ///
+  /// This is `None` only for `UnknownEventType`.
+  pub fn timestamp(&self) -> Option<u32> {
+    match *self {
+      Event::ControllerAxis { timestamp, .. } => Some(timestamp),
+      Event::ControllerButton { timestamp, .. } => Some(timestamp),
+      Event::ControllerDeviceAdded { timestamp, .. } => Some(timestamp),
+      Event::ControllerDeviceRemapped { timestamp, .. } => Some(timestamp),
+      Event::ControllerDeviceRemoved { timestamp, .. } => Some(timestamp),
+      Event::JoyDeviceAdded { timestamp, .. } => Some(timestamp),
+      Event::JoyDeviceRemoved { timestamp, .. } => Some(timestamp),
+      Event::Keyboard { timestamp, .. } => Some(timestamp),
+      Event::MouseButtonEvent { timestamp, .. } => Some(timestamp),
+      Event::MouseMotion { timestamp, .. } => Some(timestamp),
+      Event::MouseWheel { timestamp, .. } => Some(timestamp),
+      Event::Quit { timestamp } => Some(timestamp),
+      Event::TextEditing { timestamp, .. } => Some(timestamp),
+      Event::Window { timestamp, .. } => Some(timestamp),
+      Event::UnknownEventType => None,
+    }
+  }
+}
 impl From<SDL_Event> for Event {
   /// Parses "without fail", but will turn unknown events into `UnknownEventType`.
   ///
@@ -323,79 +277,28 @@ impl From<SDL_Event> for Event {
           timestamp: event.key.timestamp,
           window_id: event.key.windowID,
           is_key_down: u32::from(event.key.state) == SDL_PRESSED,
-          repeat_count: event.key.repeat,
+          repeat: event.key.repeat != 0,
           key_info: KeyInfo::from(event.key.keysym),
         },
-        SDL_WINDOWEVENT => match event.window.event as fermium::SDL_WindowEventID::Type {
-          SDL_WINDOWEVENT_MOVED => Event::WindowMoved {
-            timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
-            x: event.window.data1,
-            y: event.window.data2,
-          },
-          SDL_WINDOWEVENT_RESIZED => Event::WindowResized {
-            timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
-            width: event.window.data1,
-            height: event.window.data2,
-          },
-          SDL_WINDOWEVENT_SIZE_CHANGED => Event::WindowSizeChanged {
-            timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
-            width: event.window.data1,
-            height: event.window.data2,
-          },
-          SDL_WINDOWEVENT_CLOSE => Event::WindowClosed {
-            timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
-          },
-          SDL_WINDOWEVENT_SHOWN => Event::WindowShown {
-            timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
-          },
-          SDL_WINDOWEVENT_HIDDEN => Event::WindowHidden {
-            timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
-          },
-          SDL_WINDOWEVENT_EXPOSED => Event::WindowNeedsRepaint {
-            timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
-          },
-          SDL_WINDOWEVENT_MINIMIZED => Event::WindowMinimized {
-            timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
-          },
-          SDL_WINDOWEVENT_MAXIMIZED => Event::WindowMaximized {
-            timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
-          },
-          SDL_WINDOWEVENT_RESTORED => Event::WindowRestored {
-            timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
-          },
-          SDL_WINDOWEVENT_ENTER => Event::MouseEnteredWindow {
-            timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
-          },
-          SDL_WINDOWEVENT_LEAVE => Event::MouseLeftWindow {
-            timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
-          },
-          SDL_WINDOWEVENT_FOCUS_GAINED => Event::WindowGainedFocus {
-            timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
-          },
-          SDL_WINDOWEVENT_FOCUS_LOST => Event::WindowLostFocus {
+        SDL_TEXTEDITING => Event::TextEditing {
+          timestamp: event.edit.timestamp,
+          window_id: event.edit.windowID,
+          text: gather_fixed_string(&event.edit.text),
+          start: event.edit.start,
+          length: event.edit.length,
+        },
+        SDL_WINDOWEVENT => match WindowEvent::try_from(event.window) {
+          Ok(window_event) => Event::Window {
             timestamp: event.window.timestamp,
-            window_id: event.window.windowID,
+            event: window_event,
           },
-          SDL_CONTROLLERAXISMOTION => Event::ControllerAxis {
-            timestamp: event.caxis.timestamp,
-            joystick_id: JoystickID(event.caxis.which),
-            axis: ControllerAxis::from(event.caxis.axis),
-            value: event.caxis.value,
-          },
-          _ => Event::UnknownEventType,
+          Err(()) => Event::UnknownEventType,
+        },
+        SDL_CONTROLLERAXISMOTION => Event::ControllerAxis {
+          timestamp: event.caxis.timestamp,
+          joystick_id: JoystickID(event.caxis.which),
+          axis: ControllerAxis::from(event.caxis.axis),
+          value: event.caxis.value,
         },
         SDL_CONTROLLERBUTTONDOWN | SDL_CONTROLLERBUTTONUP => Event::ControllerButton {
           timestamp: event.cbutton.timestamp,
@@ -415,14 +318,525 @@ impl From<SDL_Event> for Event {
           timestamp: event.cdevice.timestamp,
           joystick_id: JoystickID(event.cdevice.which),
         },
+        SDL_JOYDEVICEADDED => Event::JoyDeviceAdded {
+          timestamp: event.jdevice.timestamp,
+          joystick_id: JoystickID(event.jdevice.which),
+        },
+        SDL_JOYDEVICEREMOVED => Event::JoyDeviceRemoved {
+          timestamp: event.jdevice.timestamp,
+          joystick_id: JoystickID(event.jdevice.which),
+        },
         _ => Event::UnknownEventType,
       }
     }
   }
 }
 
+/// Copies a fixed size C string buffer up to the null terminator.
+///
+/// If there's no null terminator the whole buffer is used.
+fn gather_fixed_string(buf: &[c_char]) -> String {
+  let bytes: Vec<u8> = buf
+    .iter()
+    .take_while(|&&c| c != 0)
+    .map(|&c| c as u8)
+    .collect();
+  String::from_utf8_lossy(&bytes).into_owned()
+}
+#[test]
+fn test_gather_fixed_string() {
+  let mut buf = [0 as c_char; 32];
+  buf[0] = b'h' as c_char;
+  buf[1] = b'i' as c_char;
+  assert_eq!(gather_fixed_string(&buf), "hi");
+  let full = [b'x' as c_char; 32];
+  assert_eq!(gather_fixed_string(&full).len(), 32);
+}
+
+/// The different things that can happen to a window.
+///
+/// All of these come in as an [`Event::Window`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowEvent {
+  /// The window has been shown.
+  ///
+  /// Inverse of [`WindowEvent::Hidden`].
+  Shown {
+    /// The window which was shown.
+    window_id: u32,
+  },
+  /// The window has been hidden.
+  ///
+  /// Inverse of [`WindowEvent::Shown`].
+  Hidden {
+    /// The window which was hidden.
+    window_id: u32,
+  },
+  /// The window was exposed and needs to be redrawn.
+  Exposed {
+    /// The window which needs a redraw.
+    window_id: u32,
+  },
+  /// The window has been moved.
+  Moved {
+    /// The window which was moved.
+    window_id: u32,
+    /// The new x position of the window.
+    x: i32,
+    /// The new y position of the window.
+    y: i32,
+  },
+  /// The size of the window has been changed externally.
+  ///
+  /// This event is always preceded by a [`WindowEvent::SizeChanged`], however
+  /// the inverse is not always true.
+  ///
+  /// The difference between these two events is that `Resized` is only
+  /// generated if the resize is triggered externally (by a user, their window
+  /// manager, etc), and *not* by calls to `beryllium` functions which may
+  /// change the size of a window, such as `Window::set_size`,
+  /// `Window::set_display_mode`, whereas [`WindowEvent::SizeChanged`] is sent
+  /// whenever the size of the window is changed, regardless of the cause.
+  Resized {
+    /// The window which experienced a size change.
+    window_id: u32,
+    /// The new width of the window.
+    width: i32,
+    /// The new height of the window.
+    height: i32,
+  },
+  /// Something has changed the size of the window.
+  ///
+  /// If (and only if) this was done by an external action (the user or window
+  /// manager changing the size of the window, for example), this event will be
+  /// followed by a [`WindowEvent::Resized`].
+  SizeChanged {
+    /// The window which experienced a size change.
+    window_id: u32,
+    /// The new width of the window.
+    width: i32,
+    /// The new height of the window.
+    height: i32,
+  },
+  /// The window has been minimized.
+  Minimized {
+    /// The window which has been minimized.
+    window_id: u32,
+  },
+  /// The window has been maximized.
+  Maximized {
+    /// The window which has been maximized.
+    window_id: u32,
+  },
+  /// The window has been restored to normal size and position.
+  Restored {
+    /// The window which has been restored.
+    window_id: u32,
+  },
+  /// The window has gained mouse focus.
+  ///
+  /// Inverse of [`WindowEvent::Leave`].
+  Enter {
+    /// The window which gained mouse focus.
+    window_id: u32,
+  },
+  /// The window has lost mouse focus.
+  ///
+  /// Inverse of [`WindowEvent::Enter`].
+  Leave {
+    /// The window which lost mouse focus.
+    window_id: u32,
+  },
+  /// The window has gained keyboard focus.
+  ///
+  /// Inverse of [`WindowEvent::FocusLost`].
+  FocusGained {
+    /// The window which gained keyboard focus.
+    window_id: u32,
+  },
+  /// The window has lost keyboard focus.
+  ///
+  /// Inverse of [`WindowEvent::FocusGained`].
+  FocusLost {
+    /// The window which lost keyboard focus.
+    window_id: u32,
+  },
+  /// The window manager requests that the window be closed.
+  Close {
+    /// The window which wants to be closed.
+    window_id: u32,
+  },
+  /// The window is being offered focus.
+  TakeFocus {
+    /// The window being offered focus.
+    window_id: u32,
+  },
+  /// The window had a hit test that wasn't `SDL_HITTEST_NORMAL`.
+  HitTest {
+    /// The window which was hit tested.
+    window_id: u32,
+  },
+}
+impl TryFrom<SDL_WindowEvent> for WindowEvent {
+  type Error = ();
+  fn try_from(e: SDL_WindowEvent) -> Result<Self, Self::Error> {
+    let window_id = e.windowID;
+    match e.event as fermium::SDL_WindowEventID::Type {
+      SDL_WINDOWEVENT_SHOWN => Ok(WindowEvent::Shown { window_id }),
+      SDL_WINDOWEVENT_HIDDEN => Ok(WindowEvent::Hidden { window_id }),
+      SDL_WINDOWEVENT_EXPOSED => Ok(WindowEvent::Exposed { window_id }),
+      SDL_WINDOWEVENT_MOVED => Ok(WindowEvent::Moved {
+        window_id,
+        x: e.data1,
+        y: e.data2,
+      }),
+      SDL_WINDOWEVENT_RESIZED => Ok(WindowEvent::Resized {
+        window_id,
+        width: e.data1,
+        height: e.data2,
+      }),
+      SDL_WINDOWEVENT_SIZE_CHANGED => Ok(WindowEvent::SizeChanged {
+        window_id,
+        width: e.data1,
+        height: e.data2,
+      }),
+      SDL_WINDOWEVENT_MINIMIZED => Ok(WindowEvent::Minimized { window_id }),
+      SDL_WINDOWEVENT_MAXIMIZED => Ok(WindowEvent::Maximized { window_id }),
+      SDL_WINDOWEVENT_RESTORED => Ok(WindowEvent::Restored { window_id }),
+      SDL_WINDOWEVENT_ENTER => Ok(WindowEvent::Enter { window_id }),
+      SDL_WINDOWEVENT_LEAVE => Ok(WindowEvent::Leave { window_id }),
+      SDL_WINDOWEVENT_FOCUS_GAINED => Ok(WindowEvent::FocusGained { window_id }),
+      SDL_WINDOWEVENT_FOCUS_LOST => Ok(WindowEvent::FocusLost { window_id }),
+      SDL_WINDOWEVENT_CLOSE => Ok(WindowEvent::Close { window_id }),
+      SDL_WINDOWEVENT_TAKE_FOCUS => Ok(WindowEvent::TakeFocus { window_id }),
+      SDL_WINDOWEVENT_HIT_TEST => Ok(WindowEvent::HitTest { window_id }),
+      _ => Err(()),
+    }
+  }
+}
+impl WindowEvent {
+  /// The window that the event is for.
+  pub fn window_id(&self) -> u32 {
+    match *self {
+      WindowEvent::Shown { window_id, .. } => window_id,
+      WindowEvent::Hidden { window_id, .. } => window_id,
+      WindowEvent::Exposed { window_id, .. } => window_id,
+      WindowEvent::Moved { window_id, .. } => window_id,
+      WindowEvent::Resized { window_id, .. } => window_id,
+      WindowEvent::SizeChanged { window_id, .. } => window_id,
+      WindowEvent::Minimized { window_id, .. } => window_id,
+      WindowEvent::Maximized { window_id, .. } => window_id,
+      WindowEvent::Restored { window_id, .. } => window_id,
+      WindowEvent::Enter { window_id, .. } => window_id,
+      WindowEvent::Leave { window_id, .. } => window_id,
+      WindowEvent::FocusGained { window_id, .. } => window_id,
+      WindowEvent::FocusLost { window_id, .. } => window_id,
+      WindowEvent::Close { window_id, .. } => window_id,
+      WindowEvent::TakeFocus { window_id, .. } => window_id,
+      WindowEvent::HitTest { window_id, .. } => window_id,
+    }
+  }
+}
+
+/// The types of event that SDL2 can put in the event queue.
+///
+/// For use with the event queue management methods, such as
+/// [flush_events](SDLToken::flush_events) and [has_event](SDLToken::has_event).
+/// The values are in the same order as SDL2 uses, so a range of types
+/// (`min..=max`) covers all related events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(windows, repr(i32))]
+#[cfg_attr(not(windows), repr(u32))]
+pub enum EventType {
+  /// The lowest event type value, for use with ranges.
+  FirstEvent = SDL_FIRSTEVENT,
+  /// User-requested quit.
+  Quit = SDL_QUIT,
+  /// The OS is terminating the application.
+  AppTerminating = SDL_APP_TERMINATING,
+  /// The OS is low on memory.
+  AppLowMemory = SDL_APP_LOWMEMORY,
+  /// The app is about to enter the background.
+  AppWillEnterBackground = SDL_APP_WILLENTERBACKGROUND,
+  /// The app did enter the background.
+  AppDidEnterBackground = SDL_APP_DIDENTERBACKGROUND,
+  /// The app is about to enter the foreground.
+  AppWillEnterForeground = SDL_APP_WILLENTERFOREGROUND,
+  /// The app did enter the foreground.
+  AppDidEnterForeground = SDL_APP_DIDENTERFOREGROUND,
+  /// Display state change.
+  DisplayEvent = SDL_DISPLAYEVENT,
+  /// Window state change.
+  WindowEvent = SDL_WINDOWEVENT,
+  /// System specific event.
+  SysWMEvent = SDL_SYSWMEVENT,
+  /// Key pressed.
+  KeyDown = SDL_KEYDOWN,
+  /// Key released.
+  KeyUp = SDL_KEYUP,
+  /// Keyboard text editing (composition).
+  TextEditing = SDL_TEXTEDITING,
+  /// Keyboard text input.
+  TextInput = SDL_TEXTINPUT,
+  /// Keyboard layout change.
+  KeymapChanged = SDL_KEYMAPCHANGED,
+  /// Mouse moved.
+  MouseMotion = SDL_MOUSEMOTION,
+  /// Mouse button pressed.
+  MouseButtonDown = SDL_MOUSEBUTTONDOWN,
+  /// Mouse button released.
+  MouseButtonUp = SDL_MOUSEBUTTONUP,
+  /// Mouse wheel motion.
+  MouseWheel = SDL_MOUSEWHEEL,
+  /// Joystick axis motion.
+  JoyAxisMotion = SDL_JOYAXISMOTION,
+  /// Joystick trackball motion.
+  JoyBallMotion = SDL_JOYBALLMOTION,
+  /// Joystick hat position change.
+  JoyHatMotion = SDL_JOYHATMOTION,
+  /// Joystick button pressed.
+  JoyButtonDown = SDL_JOYBUTTONDOWN,
+  /// Joystick button released.
+  JoyButtonUp = SDL_JOYBUTTONUP,
+  /// A joystick was connected.
+  JoyDeviceAdded = SDL_JOYDEVICEADDED,
+  /// An opened joystick was disconnected.
+  JoyDeviceRemoved = SDL_JOYDEVICEREMOVED,
+  /// Controller axis motion.
+  ControllerAxisMotion = SDL_CONTROLLERAXISMOTION,
+  /// Controller button pressed.
+  ControllerButtonDown = SDL_CONTROLLERBUTTONDOWN,
+  /// Controller button released.
+  ControllerButtonUp = SDL_CONTROLLERBUTTONUP,
+  /// A controller was connected.
+  ControllerDeviceAdded = SDL_CONTROLLERDEVICEADDED,
+  /// An opened controller was disconnected.
+  ControllerDeviceRemoved = SDL_CONTROLLERDEVICEREMOVED,
+  /// A controller mapping was updated.
+  ControllerDeviceRemapped = SDL_CONTROLLERDEVICEREMAPPED,
+  /// Touch started.
+  FingerDown = SDL_FINGERDOWN,
+  /// Touch ended.
+  FingerUp = SDL_FINGERUP,
+  /// Touch moved.
+  FingerMotion = SDL_FINGERMOTION,
+  /// A "dollar" gesture was performed.
+  DollarGesture = SDL_DOLLARGESTURE,
+  /// A "dollar" gesture was recorded.
+  DollarRecord = SDL_DOLLARRECORD,
+  /// A multi-finger gesture was performed.
+  MultiGesture = SDL_MULTIGESTURE,
+  /// The clipboard changed.
+  ClipboardUpdate = SDL_CLIPBOARDUPDATE,
+  /// The system requests a file open.
+  DropFile = SDL_DROPFILE,
+  /// Text was drag-and-dropped.
+  DropText = SDL_DROPTEXT,
+  /// A new set of drops is beginning.
+  DropBegin = SDL_DROPBEGIN,
+  /// The current set of drops is complete.
+  DropComplete = SDL_DROPCOMPLETE,
+  /// A new audio device is available.
+  AudioDeviceAdded = SDL_AUDIODEVICEADDED,
+  /// An audio device was removed.
+  AudioDeviceRemoved = SDL_AUDIODEVICEREMOVED,
+  /// A sensor was updated.
+  SensorUpdate = SDL_SENSORUPDATE,
+  /// The render targets were reset and their contents need to be redrawn.
+  RenderTargetsReset = SDL_RENDER_TARGETS_RESET,
+  /// The render device was reset and all textures need to be re-created.
+  RenderDeviceReset = SDL_RENDER_DEVICE_RESET,
+  /// The first of the user event types.
+  UserEvent = SDL_USEREVENT,
+  /// The highest event type value, for use with ranges.
+  LastEvent = SDL_LASTEVENT,
+}
+impl EventType {
+  /// The `(min, max)` range of all joystick events.
+  pub const JOYSTICK_EVENTS: (EventType, EventType) =
+    (EventType::JoyAxisMotion, EventType::JoyDeviceRemoved);
+  /// The `(min, max)` range of all controller events.
+  pub const CONTROLLER_EVENTS: (EventType, EventType) = (
+    EventType::ControllerAxisMotion,
+    EventType::ControllerDeviceRemapped,
+  );
+}
+
+impl SDLToken {
+  /// Pumps the OS event loop, gathering up events into SDL2's event queue.
+  ///
+  /// [poll_event](SDLToken::poll_event) does this for you, so you only need
+  /// to call this if you're looking at the queue without polling it.
+  pub fn pump_events(&self) {
+    unsafe { SDL_PumpEvents() }
+  }
+
+  /// Checks if there's an event of the type given in the queue.
+  ///
+  /// This doesn't take the event out of the queue.
+  pub fn has_event(&self, event_type: EventType) -> bool {
+    let out = unsafe { SDL_HasEvent(event_type as u32) };
+    out == SDL_TRUE
+  }
+
+  /// Checks if there's an event in the given range of types (inclusive) in
+  /// the queue.
+  ///
+  /// This doesn't take the event out of the queue.
+  pub fn has_events(&self, min: EventType, max: EventType) -> bool {
+    let out = unsafe { SDL_HasEvents(min as u32, max as u32) };
+    out == SDL_TRUE
+  }
+
+  /// Removes all events of the type given from the queue.
+  ///
+  /// This only affects events already in the queue, so you might want to
+  /// [pump_events](SDLToken::pump_events) first.
+  pub fn flush_event(&self, event_type: EventType) {
+    unsafe { SDL_FlushEvent(event_type as u32) }
+  }
+
+  /// Removes all events in the given range of types (inclusive) from the
+  /// queue.
+  ///
+  /// This only affects events already in the queue, so you might want to
+  /// [pump_events](SDLToken::pump_events) first.
+  pub fn flush_events(&self, min: EventType, max: EventType) {
+    unsafe { SDL_FlushEvents(min as u32, max as u32) }
+  }
+
+  /// Polls for an event in the given range of types (inclusive), getting it
+  /// out of the queue if one is there.
+  ///
+  /// Events of other types are left in the queue for the main loop. Like
+  /// [poll_event](SDLToken::poll_event), this pumps the event loop first.
+  ///
+  /// ```no_run
+  /// # use beryllium::EventType;
+  /// # let sdl = unsafe { beryllium::init().unwrap() };
+  /// let (min, max) = EventType::CONTROLLER_EVENTS;
+  /// while let Some(event) = sdl.poll_event_type(min, max) {
+  ///   println!("{:?}", event);
+  /// }
+  /// ```
+  pub fn poll_event_type(&self, min: EventType, max: EventType) -> Option<Event> {
+    let mut sdl_event = SDL_Event::default();
+    let out = unsafe {
+      SDL_PumpEvents();
+      SDL_PeepEvents(
+        &mut sdl_event,
+        1,
+        fermium::SDL_eventaction::SDL_GETEVENT,
+        min as u32,
+        max as u32,
+      )
+    };
+    if out == 1 {
+      Some(Event::from(sdl_event))
+    } else {
+      None
+    }
+  }
+
+  /// Iterates over all the pending events, without blocking.
+  ///
+  /// Each step is a [poll_event](SDLToken::poll_event), and the iterator ends
+  /// once the queue is empty.
+  ///
+  /// ```no_run
+  /// # let sdl = unsafe { beryllium::init().unwrap() };
+  /// for event in sdl.events() {
+  ///   println!("{:?}", event);
+  /// }
+  /// ```
+  pub fn events(&self) -> EventPollIter<'_> {
+    EventPollIter { sdl: self }
+  }
+}
+
+/// An iterator over the pending events.
+///
+/// See [events](SDLToken::events).
+#[derive(Debug)]
+pub struct EventPollIter<'sdl> {
+  sdl: &'sdl SDLToken,
+}
+impl<'sdl> Iterator for EventPollIter<'sdl> {
+  type Item = Event;
+  fn next(&mut self) -> Option<Event> {
+    self.sdl.poll_event()
+  }
+}
+
+impl SDLToken {
+  /// Adds a callback that's run on every event as it is added to the queue.
+  ///
+  /// Unlike [poll_event](SDLToken::poll_event), this fires synchronously,
+  /// _on whatever thread pushed the event_. That's usually the main thread,
+  /// but SDL2 and other code can push events from other threads too (timer
+  /// callbacks, for example), so the closure can run on any thread and even
+  /// on more than one at once. That's why it has to be `Send + Sync`.
+  /// Watching an event doesn't remove it from the queue, so you'll still see
+  /// it when you poll later.
+  ///
+  /// A panic can't unwind back through SDL2's C code, so if the closure
+  /// panics the process is aborted (after the panic message is printed).
+  ///
+  /// The watch is removed (and the closure freed) when the [EventWatch] drops.
+  pub fn add_event_watch<F: Fn(&Event) + Send + Sync + 'static>(
+    &self, callback: F,
+  ) -> EventWatch<'_> {
+    let callback: Box<EventWatchFn> = Box::new(Box::new(callback));
+    let userdata = &*callback as *const EventWatchFn as *mut c_void;
+    unsafe { SDL_AddEventWatch(Some(event_watch_trampoline), userdata) };
+    EventWatch {
+      callback,
+      _marker: PhantomData,
+    }
+  }
+}
+
+/// The boxed closure of an [EventWatch].
+type EventWatchFn = Box<dyn Fn(&Event) + Send + Sync>;
+
+/// Converts the raw event and then passes it along to the user's closure.
+unsafe extern "C" fn event_watch_trampoline(userdata: *mut c_void, event: *mut SDL_Event) -> i32 {
+  let callback = &*(userdata as *const EventWatchFn);
+  let event = Event::from(*event);
+  let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback(&event)));
+  if result.is_err() {
+    // Note: unwinding into the C caller isn't allowed, so this is the only
+    // safe thing left to do.
+    std::process::abort();
+  }
+  // Note: The return value of an event watch is ignored.
+  0
+}
+
+/// Handle to an event watch callback.
+///
+/// See [add_event_watch](SDLToken::add_event_watch). Dropping this removes the
+/// watch.
+pub struct EventWatch<'sdl> {
+  callback: Box<EventWatchFn>,
+  _marker: PhantomData<&'sdl SDLToken>,
+}
+impl core::fmt::Debug for EventWatch<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "EventWatch({:p})", &*self.callback)
+  }
+}
+impl Drop for EventWatch<'_> {
+  fn drop(&mut self) {
+    // Note: SDL2 identifies the watch by the (filter, userdata)
+    // pair, so we must pass back the exact same pointer we registered with.
+    let userdata = &*self.callback as *const EventWatchFn as *mut c_void;
+    unsafe { SDL_DelEventWatch(Some(event_watch_trampoline), userdata) };
+  }
+}
+
 /// The possible mouse buttons.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MouseButton {
   /// Left side
   Left,
@@ -476,6 +890,94 @@ impl MouseButtonState {
   }
 }
 
+/// A snapshot of the mouse's position and buttons.
+///
+/// See [mouse_state](SDLToken::mouse_state) and
+/// [global_mouse_state](SDLToken::global_mouse_state).
+#[derive(Debug, Clone, Copy)]
+pub struct MouseState {
+  /// Horizontal position.
+  pub x: i32,
+  /// Vertical position.
+  pub y: i32,
+  /// The buttons held down.
+  pub buttons: MouseButtonState,
+}
+
+impl SDLToken {
+  /// Gets the mouse state relative to the window with mouse focus.
+  ///
+  /// This is the state as of the last event pump, so it's in the same time
+  /// frame as the events you've polled.
+  pub fn mouse_state(&self) -> MouseState {
+    let mut x = 0;
+    let mut y = 0;
+    let buttons = unsafe { SDL_GetMouseState(&mut x, &mut y) };
+    MouseState {
+      x,
+      y,
+      buttons: MouseButtonState(buttons),
+    }
+  }
+
+  /// Gets the mouse state in desktop coordinates.
+  ///
+  /// This works even when the mouse is outside of all of your windows, which
+  /// is mostly useful with a mouse capture during a drag. Unlike
+  /// [mouse_state](SDLToken::mouse_state) this asks the OS directly, so it's
+  /// the "right now" position rather than the position as of the last event
+  /// pump.
+  ///
+  /// (SDL 2.0.4 or later)
+  pub fn global_mouse_state(&self) -> MouseState {
+    let mut x = 0;
+    let mut y = 0;
+    let buttons = unsafe { SDL_GetGlobalMouseState(&mut x, &mut y) };
+    MouseState {
+      x,
+      y,
+      buttons: MouseButtonState(buttons),
+    }
+  }
+}
+
+impl SDLToken {
+  /// Starts accepting text input.
+  ///
+  /// While text input is on you'll get [TextEditing](Event::TextEditing)
+  /// events. On devices with a [screen
+  /// keyboard](SDLToken::has_screen_keyboard_support), this also shows it.
+  pub fn start_text_input(&self) {
+    unsafe { SDL_StartTextInput() }
+  }
+
+  /// Stops accepting text input.
+  ///
+  /// This also hides the screen keyboard, if there's one showing.
+  pub fn stop_text_input(&self) {
+    unsafe { SDL_StopTextInput() }
+  }
+
+  /// Checks if text input is currently on.
+  pub fn is_text_input_active(&self) -> bool {
+    SDL_TRUE == unsafe { SDL_IsTextInputActive() }
+  }
+
+  /// Checks if the platform has a screen keyboard (as on phones and tablets).
+  ///
+  /// There's no separate call to show or hide the screen keyboard. SDL2 shows
+  /// it when you [start_text_input](SDLToken::start_text_input) and hides it
+  /// when you [stop_text_input](SDLToken::stop_text_input).
+  pub fn has_screen_keyboard_support(&self) -> bool {
+    SDL_TRUE == unsafe { SDL_HasScreenKeyboardSupport() }
+  }
+
+  /// Checks if the screen keyboard is being shown for the window given.
+  pub fn is_screen_keyboard_shown(&self, window: &Window) -> bool {
+    SDL_TRUE == unsafe { SDL_IsScreenKeyboardShown(window.ptr) }
+  }
+}
+
 /// Information
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct KeyInfo {
@@ -1274,6 +1776,14 @@ pub enum Scancode {
   Y = SDL_SCANCODE_Y,
   Z = SDL_SCANCODE_Z,
 }
+impl Scancode {
+  /// Gets a human readable name for the scancode.
+  ///
+  /// If the scancode doesn't have a name you get an empty string.
+  pub fn name(self) -> String {
+    unsafe { gather_string(SDL_GetScancodeName(self as fermium::SDL_Scancode::Type)) }
+  }
+}
 impl TryFrom<fermium::SDL_Scancode::Type> for Scancode {
   type Error = ();
   fn try_from(t: fermium::SDL_Scancode::Type) -> Result<Self, Self::Error> {
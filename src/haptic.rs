@@ -0,0 +1,81 @@
+use super::*;
+
+impl SDLToken {
+  /// Attempts to open the force feedback device of a joystick.
+  ///
+  /// Not all joysticks have force feedback, so this can fail.
+  pub fn open_haptic_from_joystick<'sdl, 'joy>(
+    &'sdl self, joystick: &'joy Joystick<'sdl>,
+  ) -> Result<Haptic<'sdl, 'joy>, String> {
+    let ptr = unsafe { SDL_HapticOpenFromJoystick(joystick.ptr) };
+    if ptr.is_null() {
+      Err(get_error())
+    } else {
+      Ok(Haptic {
+        ptr,
+        _marker: PhantomData,
+      })
+    }
+  }
+}
+
+/// Handle to a force feedback ("haptic") device.
+///
+/// This is opened from a [Joystick], and it has to be closed before the
+/// joystick is, so it borrows the joystick.
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Haptic<'sdl, 'joy> {
+  pub(crate) ptr: *mut SDL_Haptic,
+  pub(crate) _marker: PhantomData<&'joy Joystick<'sdl>>,
+}
+impl<'sdl, 'joy> Drop for Haptic<'sdl, 'joy> {
+  fn drop(&mut self) {
+    unsafe { SDL_HapticClose(self.ptr) }
+  }
+}
+impl<'sdl, 'joy> Haptic<'sdl, 'joy> {
+  /// Checks if the device supports simple rumble.
+  pub fn rumble_supported(&self) -> Result<bool, String> {
+    match unsafe { SDL_HapticRumbleSupported(self.ptr) } {
+      0 => Ok(false),
+      1 => Ok(true),
+      _ => Err(get_error()),
+    }
+  }
+
+  /// Gets the device ready for simple rumble.
+  ///
+  /// You have to call this before [rumble_play](Haptic::rumble_play).
+  pub fn rumble_init(&self) -> Result<(), String> {
+    let out = unsafe { SDL_HapticRumbleInit(self.ptr) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Plays a simple rumble.
+  ///
+  /// * `strength` is from `0.0` to `1.0`, values outside that are clamped.
+  /// * `duration_ms` is how long to rumble for, in milliseconds.
+  pub fn rumble_play(&self, strength: f32, duration_ms: u32) -> Result<(), String> {
+    let out = unsafe { SDL_HapticRumblePlay(self.ptr, strength.clamp(0.0, 1.0), duration_ms) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Stops any simple rumble that's playing.
+  pub fn rumble_stop(&self) -> Result<(), String> {
+    let out = unsafe { SDL_HapticRumbleStop(self.ptr) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+}
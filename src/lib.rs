@@ -71,6 +71,14 @@
 //! to be glib about it, but calling _arbitrary_ code can't be safety checked.
 //! That said, it's an important end user ability that should not be removed.
 //!
+//! ## SDL2 Version
+//!
+//! `beryllium` binds to SDL 2.0.9 (through fermium 0.0.15), so nothing that
+//! SDL2 added after that is available: `SDL_RenderGeometry`, float rects and
+//! float rendering, `SDL_SetTextureScaleMode`, `SDL_RenderSetVSync`,
+//! `SDL_FlashWindow`, and so on. A few methods do the same job some other way
+//! (on the CPU, or through a hint), and their docs say how they work.
+//!
 //! ## Rewritten In Rust
 //!
 //! Select portions of the SDL2 API _have_ been re-written entirely in Rust
@@ -92,18 +100,23 @@
 //! parts have been rewritten in Rust.
 
 use core::{
+  cell::{Cell, RefCell},
   convert::TryFrom,
   ffi::c_void,
   marker::PhantomData,
+  mem::ManuallyDrop,
+  ops::{Deref, DerefMut},
   ptr::{null, null_mut, NonNull},
   slice::from_raw_parts,
   sync::atomic::{AtomicBool, Ordering},
+  time::Duration,
 };
 
 use fermium::{
-  SDL_EventType::*, SDL_GLattr::*, SDL_GLcontextFlag::*, SDL_GLprofile::*,
-  SDL_GameControllerAxis::*, SDL_GameControllerButton::*, SDL_Keymod::*, SDL_RendererFlags::*,
-  SDL_Scancode::*, SDL_WindowEventID::*, SDL_WindowFlags::*, SDL_bool::*, _bindgen_ty_1::*,
+  SDL_BlendMode::*, SDL_EventType::*, SDL_GLattr::*, SDL_GLcontextFlag::*, SDL_GLprofile::*,
+  SDL_GameControllerAxis::*, SDL_GameControllerButton::*, SDL_JoystickPowerLevel::*,
+  SDL_Keymod::*, SDL_RendererFlags::*, SDL_RendererFlip::*, SDL_Scancode::*,
+  SDL_TextureAccess::*, SDL_WindowEventID::*, SDL_WindowFlags::*, SDL_bool::*, _bindgen_ty_1::*,
   _bindgen_ty_2::*, _bindgen_ty_3::*, _bindgen_ty_4::*, _bindgen_ty_5::*, _bindgen_ty_6::*,
   _bindgen_ty_7::*, *,
 };
@@ -147,6 +160,27 @@ pub use texture::*;
 mod rect;
 pub use rect::*;
 
+mod blend_mode;
+pub use blend_mode::*;
+
+mod subsystem;
+pub use subsystem::*;
+
+mod haptic;
+pub use haptic::*;
+
+mod platform;
+pub use platform::*;
+
+mod sprite_list;
+pub use sprite_list::*;
+
+mod pixels;
+pub use pixels::*;
+
+mod draw_target;
+pub use draw_target::*;
+
 /// In case of emergency, you can break the glass.
 pub use fermium as unsafe_raw_ffi;
 
@@ -200,6 +234,28 @@ pub fn get_error() -> String {
   unsafe { gather_string(SDL_GetError()) }
 }
 
+/// Clears the current SDL2 error string.
+///
+/// Useful if you want to be sure that an error you see later wasn't just left
+/// over from some earlier and unrelated failure.
+pub fn clear_error() {
+  unsafe { SDL_ClearError() }
+}
+
+/// Sets the current SDL2 error string.
+///
+/// The message is used as-is (it's not a format string). If it contains a
+/// null byte the message is cut short at that point.
+pub fn set_error(message: &str) {
+  let message_null: Vec<u8> = message.bytes().chain(Some(0)).collect();
+  unsafe {
+    SDL_SetError(
+      b"%s\0".as_ptr() as *const c_char,
+      message_null.as_ptr() as *const c_char,
+    )
+  };
+}
+
 /// The kind of message box you wish to show.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(windows, repr(i32))]
@@ -238,6 +294,89 @@ pub unsafe fn lone_message_box(
   }
 }
 
+/// A button for a [show_message_box] dialog.
+#[derive(Debug, Clone, Copy)]
+pub struct MessageBoxButton<'a> {
+  /// The value you get back if this button is picked.
+  pub id: i32,
+  /// The button's text.
+  pub text: &'a str,
+  /// If pressing Return picks this button.
+  pub return_key_default: bool,
+  /// If pressing Escape picks this button.
+  pub escape_key_default: bool,
+}
+
+/// All the settings for a [show_message_box] dialog.
+#[derive(Debug, Clone)]
+pub struct MessageBoxConfig<'a> {
+  /// The kind of message box.
+  pub box_type: MessageBox,
+  /// The window title.
+  pub title: &'a str,
+  /// The message text.
+  pub message: &'a str,
+  /// A window that the message box should be modal to, if any.
+  pub parent: Option<&'a Window<'a>>,
+  /// The buttons to show, in order.
+  pub buttons: Vec<MessageBoxButton<'a>>,
+}
+
+/// Shows a message box with custom buttons, and gives the `id` of the button
+/// that was picked.
+///
+/// If the box is closed without any button being picked you get `None`.
+///
+/// Like with [lone_message_box], this doesn't require SDL2 to be initialized
+/// unless you're using a parent window.
+///
+/// # Safety
+///
+/// As with all GUI things, you must only call this from the main thread.
+pub unsafe fn show_message_box(config: MessageBoxConfig) -> Result<Option<i32>, String> {
+  let title_null: Vec<u8> = config.title.bytes().chain(Some(0)).collect();
+  let message_null: Vec<u8> = config.message.bytes().chain(Some(0)).collect();
+  let texts_null: Vec<Vec<u8>> = config
+    .buttons
+    .iter()
+    .map(|b| b.text.bytes().chain(Some(0)).collect())
+    .collect();
+  let buttons: Vec<SDL_MessageBoxButtonData> = config
+    .buttons
+    .iter()
+    .zip(texts_null.iter())
+    .map(|(b, text_null)| {
+      let mut flags = 0;
+      if b.return_key_default {
+        flags |= fermium::SDL_MessageBoxButtonFlags::SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT;
+      }
+      if b.escape_key_default {
+        flags |= fermium::SDL_MessageBoxButtonFlags::SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT;
+      }
+      SDL_MessageBoxButtonData {
+        flags: flags as _,
+        buttonid: b.id,
+        text: text_null.as_ptr() as *const c_char,
+      }
+    })
+    .collect();
+  let data = SDL_MessageBoxData {
+    flags: config.box_type as u32,
+    window: config.parent.map(|w| w.ptr).unwrap_or(null_mut()),
+    title: title_null.as_ptr() as *const c_char,
+    message: message_null.as_ptr() as *const c_char,
+    numbuttons: buttons.len() as i32,
+    buttons: buttons.as_ptr(),
+    colorScheme: null(),
+  };
+  let mut button_id = -1;
+  if SDL_ShowMessageBox(&data, &mut button_id) == 0 {
+    Ok(if button_id < 0 { None } else { Some(button_id) })
+  } else {
+    Err(get_error())
+  }
+}
+
 /// Initializes SDL2 and gives you a token as proof, or an error message.
 ///
 /// # Failure
@@ -256,6 +395,7 @@ pub unsafe fn init() -> Result<SDLToken, String> {
     // that means it was already active, so that's an error.
     Err("The library is currently initialized!".to_string())
   } else if SDL_Init(SDL_INIT_EVERYTHING) == 0 {
+    IS_SDL2_INIT_THREAD.with(|flag| flag.set(true));
     Ok(SDLToken {
       _marker: PhantomData,
     })
@@ -266,6 +406,19 @@ pub unsafe fn init() -> Result<SDLToken, String> {
   }
 }
 static I_THINK_THAT_SDL2_IS_ACTIVE: AtomicBool = AtomicBool::new(false);
+std::thread_local! {
+  // Note: This is a thread local flag rather than a field so that `SDLToken`
+  // stays zero sized.
+  static IS_SDL2_INIT_THREAD: Cell<bool> = const { Cell::new(false) };
+}
+
+/// In debug builds, panics if this isn't the thread that initialized SDL2.
+fn debug_assert_init_thread() {
+  debug_assert!(
+    IS_SDL2_INIT_THREAD.with(|flag| flag.get()),
+    "beryllium error: SDL2 must be used from the thread that called `init` (the main thread)."
+  );
+}
 
 /// The `SDLToken` is proof that you have initialized SDL2.
 ///
@@ -279,6 +432,7 @@ pub struct SDLToken {
 impl Drop for SDLToken {
   fn drop(&mut self) {
     unsafe { SDL_Quit() }
+    IS_SDL2_INIT_THREAD.with(|flag| flag.set(false));
     I_THINK_THAT_SDL2_IS_ACTIVE.store(false, Ordering::SeqCst);
   }
 }
@@ -287,6 +441,48 @@ fn test_sdl_token_zero_size() {
   assert_eq!(core::mem::size_of::<SDLToken>(), 0)
 }
 impl SDLToken {
+  /// Checks (in debug builds) that this is the thread that initialized SDL2.
+  ///
+  /// The video and event parts of SDL2 only work on the main thread (and on
+  /// macOS, getting this wrong tends to be a crash deep in the OS). The
+  /// `SDLToken` isn't `Send`, so normally you can't get this wrong, but
+  /// callbacks (event watches, audio, timers) run on other threads, and it's
+  /// easy to smuggle a pointer into one of those. This turns that mistake into
+  /// a clear panic instead.
+  ///
+  /// This is already called by [poll_event](SDLToken::poll_event),
+  /// [create_window](SDLToken::create_window), and
+  /// [create_renderer](Window::create_renderer). In release builds it does
+  /// nothing.
+  pub fn assert_main_thread(&self) {
+    debug_assert_init_thread()
+  }
+
+  /// Waits for the duration given, more precisely than a plain sleep.
+  ///
+  /// `SDL_Delay` only works in whole milliseconds and often oversleeps a bit,
+  /// which shows up as jitter if you use it to pace frames. This sleeps with
+  /// `SDL_Delay` for all but the last millisecond, then busy-waits on the
+  /// performance counter until the time is up.
+  ///
+  /// The busy-wait keeps a CPU core fully busy for up to about a millisecond
+  /// each call (longer if the sleep came back early). That's usually fine
+  /// once per frame, but don't use this for long waits in a loop where a
+  /// plain sleep would do.
+  pub fn precise_delay(&self, duration: Duration) {
+    let frequency = unsafe { SDL_GetPerformanceFrequency() };
+    let start = unsafe { SDL_GetPerformanceCounter() };
+    let ticks = duration.as_nanos() * u128::from(frequency) / 1_000_000_000;
+    let ticks = u64::try_from(ticks).unwrap_or(u64::MAX);
+    let sleep_ms = duration.as_millis().saturating_sub(1);
+    if sleep_ms > 0 {
+      unsafe { SDL_Delay(u32::try_from(sleep_ms).unwrap_or(u32::MAX)) };
+    }
+    while unsafe { SDL_GetPerformanceCounter() }.wrapping_sub(start) < ticks {
+      core::hint::spin_loop();
+    }
+  }
+
   /// Creates a new window, or gives an error message.
   ///
   /// Note that not all possible flags have an effect! See [the
@@ -294,6 +490,7 @@ impl SDLToken {
   pub fn create_window<'sdl>(
     &'sdl self, title: &str, x: i32, y: i32, w: i32, h: i32, flags: WindowFlags,
   ) -> Result<Window<'sdl>, String> {
+    self.assert_main_thread();
     let title_null: Vec<u8> = title.bytes().chain(Some(0)).collect();
     let ptr = unsafe {
       SDL_CreateWindow(
@@ -357,6 +554,7 @@ impl SDLToken {
 
   /// Polls for an event, getting it out of the queue if one is there.
   pub fn poll_event(&self) -> Option<Event> {
+    self.assert_main_thread();
     unsafe {
       let mut sdl_event = SDL_Event::default();
       if SDL_PollEvent(&mut sdl_event) == 1 {
@@ -392,6 +590,30 @@ impl SDLToken {
     }
   }
 
+  /// The same as [number_of_joysticks](SDLToken::number_of_joysticks), using
+  /// the SDL2 name.
+  pub fn num_joysticks(&self) -> Result<i32, String> {
+    self.number_of_joysticks()
+  }
+
+  /// Says if the device index supports the Controller API.
+  ///
+  /// The same as
+  /// [joystick_is_game_controller](SDLToken::joystick_is_game_controller),
+  /// but with a plain index, which is handy when you're going over
+  /// `0..num_joysticks()` before opening anything.
+  pub fn is_game_controller(&self, index: i32) -> bool {
+    self.joystick_is_game_controller(JoystickID(index))
+  }
+
+  /// Given a device index, attempts to get the Controller name, if any.
+  ///
+  /// The same as [controller_name](SDLToken::controller_name), but with a
+  /// plain index.
+  pub fn controller_name_for_index(&self, index: i32) -> Option<String> {
+    self.controller_name(JoystickID(index))
+  }
+
   /// Attempts to open the given id as a [Controller].
   ///
   /// Not all joysticks support the Controller API, so this can fail.
@@ -407,6 +629,41 @@ impl SDLToken {
     }
   }
 
+  /// Gets another handle to an already open [Controller], using its instance
+  /// id.
+  ///
+  /// Events tell you the instance id of the controller involved, and this lets
+  /// you find the controller to match. If no open controller has that instance
+  /// id you get `None`.
+  ///
+  /// SDL2 counts how many times each controller is opened, so the new handle
+  /// and the handle you already had are both valid, and the controller stays
+  /// open until all handles to it have been dropped.
+  pub fn controller_from_instance_id(&self, id: JoystickID) -> Option<Controller<'_>> {
+    if unsafe { SDL_GameControllerFromInstanceID(id.0) }.is_null() {
+      return None;
+    }
+    let count = unsafe { SDL_NumJoysticks() };
+    let index = (0..count).find(|&i| unsafe { SDL_JoystickGetDeviceInstanceID(i) } == id.0)?;
+    self.open_controller(JoystickID(index)).ok()
+  }
+
+  /// Attempts to open the given id as a [Joystick].
+  ///
+  /// This is the lower level API that works with any joystick, even ones that
+  /// don't support the Controller API.
+  pub fn open_joystick(&self, id: JoystickID) -> Result<Joystick<'_>, String> {
+    let ptr = unsafe { SDL_JoystickOpen(id.0) };
+    if ptr.is_null() {
+      Err(get_error())
+    } else {
+      Ok(Joystick {
+        ptr,
+        _marker: PhantomData,
+      })
+    }
+  }
+
   /// Attempts to load the named dynamic library into the program.
   pub fn load_cdylib<'sdl>(&'sdl self, name: &str) -> Result<CDyLib<'sdl>, String> {
     let name_null: Vec<u8> = name.bytes().chain(Some(0)).collect();
@@ -488,10 +745,11 @@ impl SDLToken {
 ///
 /// Used with some parts of the [Renderer].
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[allow(missing_docs)]
 #[repr(C)]
 pub struct Point {
-  x: i32,
-  y: i32,
+  pub x: i32,
+  pub y: i32,
 }
 impl From<SDL_Point> for Point {
   fn from(other: SDL_Point) -> Self {
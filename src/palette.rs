@@ -23,6 +23,143 @@ pub struct Color {
   /// Alpha / opacity
   pub a: u8,
 }
+impl Color {
+  /// Opaque white.
+  pub const WHITE: Self = Self::rgb(255, 255, 255);
+  /// Opaque black.
+  pub const BLACK: Self = Self::rgb(0, 0, 0);
+  /// Opaque red.
+  pub const RED: Self = Self::rgb(255, 0, 0);
+  /// Opaque green.
+  pub const GREEN: Self = Self::rgb(0, 255, 0);
+  /// Opaque blue.
+  pub const BLUE: Self = Self::rgb(0, 0, 255);
+  /// Opaque yellow.
+  pub const YELLOW: Self = Self::rgb(255, 255, 0);
+  /// Opaque cyan.
+  pub const CYAN: Self = Self::rgb(0, 255, 255);
+  /// Opaque magenta.
+  pub const MAGENTA: Self = Self::rgb(255, 0, 255);
+  /// Fully transparent black.
+  pub const TRANSPARENT: Self = Self::rgba(0, 0, 0, 0);
+
+  /// Makes an opaque color.
+  pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+    Self { r, g, b, a: 255 }
+  }
+
+  /// Makes a color with the alpha given.
+  pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+    Self { r, g, b, a }
+  }
+
+  /// This color with each of red, green, and blue flipped around (alpha is
+  /// kept).
+  pub fn inverted(&self) -> Color {
+    Color::rgba(255 - self.r, 255 - self.g, 255 - self.b, self.a)
+  }
+
+  /// This color as a shade of gray with the same brightness (alpha is kept).
+  ///
+  /// The brightness is `0.299 * r + 0.587 * g + 0.114 * b`.
+  pub fn grayscale(&self) -> Color {
+    let luma = 0.299 * f32::from(self.r) + 0.587 * f32::from(self.g) + 0.114 * f32::from(self.b);
+    let luma = luma.round().min(255.0) as u8;
+    Color::rgba(luma, luma, luma, self.a)
+  }
+
+  /// Converts this color into linear light values.
+  ///
+  /// `Color` values are sRGB encoded, which is good for storage but not for
+  /// math. See [blend_linear](Color::blend_linear).
+  pub fn to_linear(&self) -> LinearColor {
+    LinearColor {
+      r: srgb_to_linear(self.r),
+      g: srgb_to_linear(self.g),
+      b: srgb_to_linear(self.b),
+      a: f32::from(self.a) / 255.0,
+    }
+  }
+
+  /// Blends from this color to the other color, with gamma correction.
+  ///
+  /// `t` is how far along to go, 0.0 gives this color and 1.0 gives `other`
+  /// (it's clamped to that range). A plain lerp of the sRGB values makes
+  /// midtones that are too dark ("muddy"), so instead the colors are turned
+  /// into linear light, mixed, and then turned back. Alpha is mixed linearly
+  /// either way.
+  pub fn blend_linear(&self, other: Color, t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    let a = self.to_linear();
+    let b = other.to_linear();
+    LinearColor {
+      r: a.r + (b.r - a.r) * t,
+      g: a.g + (b.g - a.g) * t,
+      b: a.b + (b.b - a.b) * t,
+      a: a.a + (b.a - a.a) * t,
+    }
+    .to_srgb()
+  }
+
+  /// Makes a color from a packed `0xRRGGBBAA` value.
+  pub const fn from_rgba_u32(packed: u32) -> Self {
+    Self {
+      r: (packed >> 24) as u8,
+      g: (packed >> 16) as u8,
+      b: (packed >> 8) as u8,
+      a: packed as u8,
+    }
+  }
+}
+/// A color in linear light, with each channel from 0.0 to 1.0.
+///
+/// Made with [Color::to_linear].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LinearColor {
+  /// Red
+  pub r: f32,
+  /// Green
+  pub g: f32,
+  /// Blue
+  pub b: f32,
+  /// Alpha / opacity (which is always linear)
+  pub a: f32,
+}
+impl LinearColor {
+  /// Converts back to an sRGB encoded [Color].
+  ///
+  /// Values outside of 0.0 to 1.0 are clamped.
+  pub fn to_srgb(&self) -> Color {
+    Color {
+      r: linear_to_srgb(self.r),
+      g: linear_to_srgb(self.g),
+      b: linear_to_srgb(self.b),
+      a: (self.a.clamp(0.0, 1.0) * 255.0).round() as u8,
+    }
+  }
+}
+
+/// The sRGB transfer function, from a `u8` channel to linear light.
+fn srgb_to_linear(channel: u8) -> f32 {
+  let c = f32::from(channel) / 255.0;
+  if c <= 0.04045 {
+    c / 12.92
+  } else {
+    ((c + 0.055) / 1.055).powf(2.4)
+  }
+}
+
+/// The inverse sRGB transfer function, from linear light to a `u8` channel.
+fn linear_to_srgb(linear: f32) -> u8 {
+  let l = linear.clamp(0.0, 1.0);
+  let c = if l <= 0.003_130_8 {
+    l * 12.92
+  } else {
+    1.055 * l.powf(1.0 / 2.4) - 0.055
+  };
+  (c * 255.0).round() as u8
+}
+
 impl From<SDL_Color> for Color {
   fn from(other: SDL_Color) -> Self {
     Self {
@@ -0,0 +1,82 @@
+use super::*;
+
+// Note: SDL_ConvertPixels doesn't need SDL2 to be initialized, so this is a
+// free function.
+
+/// Converts a block of pixels from one format to another.
+///
+/// * `src_pitch` and `dst_pitch` are the number of bytes from the start of one
+///   row to the start of the next, which is at least `width` times the bytes
+///   per pixel of the format.
+/// * Both buffers must be big enough for `height` rows at their pitch (the
+///   last row only needs to be `width` pixels long).
+///
+/// Formats with less than 8 bits per pixel, and FourCC formats, aren't
+/// supported.
+#[allow(clippy::too_many_arguments)]
+pub fn convert_pixels(
+  width: i32, height: i32, src_format: PixelFormatEnum, src: &[u8], src_pitch: usize,
+  dst_format: PixelFormatEnum, dst: &mut [u8], dst_pitch: usize,
+) -> Result<(), String> {
+  if width < 0 || height < 0 {
+    return Err("beryllium error: width and height can't be negative.".to_string());
+  }
+  if src_pitch > i32::MAX as usize || dst_pitch > i32::MAX as usize {
+    return Err("beryllium error: pitch cannot exceed `i32::MAX`.".to_string());
+  }
+  let src_len = required_len(width, height, src_format, src_pitch)?;
+  if src.len() < src_len {
+    return Err("beryllium error: the source buffer is too small.".to_string());
+  }
+  let dst_len = required_len(width, height, dst_format, dst_pitch)?;
+  if dst.len() < dst_len {
+    return Err("beryllium error: the destination buffer is too small.".to_string());
+  }
+  let out = unsafe {
+    SDL_ConvertPixels(
+      width,
+      height,
+      src_format as u32,
+      src.as_ptr() as *const c_void,
+      src_pitch as i32,
+      dst_format as u32,
+      dst.as_mut_ptr() as *mut c_void,
+      dst_pitch as i32,
+    )
+  };
+  if out == 0 {
+    Ok(())
+  } else {
+    Err(get_error())
+  }
+}
+
+/// The number of bytes a `width` by `height` block of pixels needs.
+fn required_len(
+  width: i32, height: i32, format: PixelFormatEnum, pitch: usize,
+) -> Result<usize, String> {
+  let bytes_per_pixel = format.bytes_per_pixel() as usize;
+  if bytes_per_pixel == 0 || format.is_fourcc() {
+    return Err("beryllium error: unsupported pixel format.".to_string());
+  }
+  let row_len = width as usize * bytes_per_pixel;
+  if pitch < row_len {
+    return Err("beryllium error: pitch is less than the row length.".to_string());
+  }
+  if height == 0 {
+    return Ok(0);
+  }
+  pitch
+    .checked_mul(height as usize - 1)
+    .and_then(|len| len.checked_add(row_len))
+    .ok_or_else(|| "beryllium error: the pixel data is too large.".to_string())
+}
+#[test]
+fn test_required_len() {
+  let format = PixelFormatEnum::RGBA8888;
+  assert_eq!(required_len(2, 3, format, 8), Ok(24));
+  assert_eq!(required_len(2, 3, format, 12), Ok(32));
+  assert_eq!(required_len(2, 0, format, 8), Ok(0));
+  assert!(required_len(2, 3, format, 7).is_err());
+  assert!(required_len(2, 3, PixelFormatEnum::Index1lsb, 8).is_err());
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+// Note: None of these need SDL2 to be initialized, so they're free functions.
+
+/// Gets the name of the platform that SDL2 was built for.
+///
+/// Such as "Windows", "Mac OS X", "Linux", "iOS", or "Android".
+pub fn platform() -> String {
+  unsafe { gather_string(SDL_GetPlatform()) }
+}
+
+/// Gets the number of logical CPU cores available.
+pub fn cpu_count() -> i32 {
+  unsafe { SDL_GetCPUCount() }
+}
+
+/// Gets the amount of system RAM, in megabytes.
+pub fn system_ram_mb() -> i32 {
+  unsafe { SDL_GetSystemRAM() }
+}
+
+/// If the CPU has SSE2 features.
+pub fn has_sse2() -> bool {
+  SDL_TRUE == unsafe { SDL_HasSSE2() }
+}
+
+/// If the CPU has SSE4.1 features.
+pub fn has_sse41() -> bool {
+  SDL_TRUE == unsafe { SDL_HasSSE41() }
+}
+
+/// If the CPU has AVX features.
+pub fn has_avx() -> bool {
+  SDL_TRUE == unsafe { SDL_HasAVX() }
+}
+
+/// If the CPU has AVX2 features.
+pub fn has_avx2() -> bool {
+  SDL_TRUE == unsafe { SDL_HasAVX2() }
+}
+
+/// If the CPU has NEON (ARM SIMD) features.
+pub fn has_neon() -> bool {
+  SDL_TRUE == unsafe { SDL_HasNEON() }
+}
@@ -138,9 +138,218 @@ impl Rect {
     }
   }
 
+  /// Grows the rect by `dx` on the left and right and by `dy` on the top and
+  /// bottom, keeping the same center.
+  ///
+  /// Negative values shrink the rect instead. If it would shrink past nothing
+  /// then that dimension is clamped to 0 (at the old center). Values that
+  /// don't fit in an `i32` saturate at its limits.
+  pub fn inflate(&self, dx: i32, dy: i32) -> Rect {
+    let (x, w) = inflate_span(self.x, self.w, dx);
+    let (y, h) = inflate_span(self.y, self.h, dy);
+    Rect { x, y, w, h }
+  }
+
+  /// Moves the rect by the given amounts, keeping the same size.
+  ///
+  /// The position saturates at the `i32` limits.
+  pub fn offset(&self, dx: i32, dy: i32) -> Rect {
+    Rect {
+      x: self.x.saturating_add(dx),
+      y: self.y.saturating_add(dy),
+      ..*self
+    }
+  }
+
+  /// The center point of the rect (rounded towards the origin).
+  pub fn center(&self) -> Point {
+    Point {
+      x: self.x.saturating_add(self.w / 2),
+      y: self.y.saturating_add(self.h / 2),
+    }
+  }
+
+  /// Makes a rect of the given size that has its [center](Rect::center) at the
+  /// point given.
+  pub fn centered_on(point: Point, w: i32, h: i32) -> Rect {
+    Rect {
+      x: point.x.saturating_sub(w / 2),
+      y: point.y.saturating_sub(h / 2),
+      w,
+      h,
+    }
+  }
+
+  /// Scales all four components of the rect, rounding to the nearest integer.
+  ///
+  /// This is a scaling of the _coordinate space_ (eg: for a DPI change), so the
+  /// position moves too, not just the size. A negative factor gives a width
+  /// and height of 0.
+  pub fn scaled(&self, factor: f32) -> Rect {
+    let scale = |v: i32| (v as f32 * factor).round() as i32;
+    Rect {
+      x: scale(self.x),
+      y: scale(self.y),
+      w: scale(self.w).max(0),
+      h: scale(self.h).max(0),
+    }
+  }
+
+  /// Converts to an `FRect`.
+  pub fn to_f(&self) -> FRect {
+    FRect {
+      x: self.x as f32,
+      y: self.y as f32,
+      w: self.w as f32,
+      h: self.h as f32,
+    }
+  }
+
+  /// An iterator over every integer point within the rect.
+  ///
+  /// This goes in row-major order, from `(x, y)` to `(x+w-1, y+h-1)`. A rect
+  /// with a zero or negative width or height has no points.
+  pub fn points(&self) -> RectPoints {
+    let total = if self.w > 0 && self.h > 0 {
+      self.w as u64 * self.h as u64
+    } else {
+      0
+    };
+    RectPoints {
+      x: self.x,
+      y: self.y,
+      w: self.w.max(1) as u64,
+      next: 0,
+      total,
+    }
+  }
+
   // TODO: SDL_EnclosePoints
 
   // TODO: SDL_IntersectRectAndLine
 
   // TODO: SDL_GetSpanEnclosingRect
 }
+
+/// The iterator from [Rect::points].
+#[derive(Debug, Clone)]
+pub struct RectPoints {
+  x: i32,
+  y: i32,
+  w: u64,
+  next: u64,
+  total: u64,
+}
+impl Iterator for RectPoints {
+  type Item = Point;
+
+  fn next(&mut self) -> Option<Point> {
+    if self.next < self.total {
+      let point = Point {
+        x: self.x + (self.next % self.w) as i32,
+        y: self.y + (self.next / self.w) as i32,
+      };
+      self.next += 1;
+      Some(point)
+    } else {
+      None
+    }
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match usize::try_from(self.total - self.next) {
+      Ok(remaining) => (remaining, Some(remaining)),
+      Err(_) => (usize::MAX, None),
+    }
+  }
+}
+
+/// Inflates a single span (`start`, `len`) by `delta` on each side.
+fn inflate_span(start: i32, len: i32, delta: i32) -> (i32, i32) {
+  let new_len = len.saturating_add(delta.saturating_mul(2));
+  if new_len < 0 {
+    (start.saturating_add(len / 2), 0)
+  } else {
+    (start.saturating_sub(delta), new_len)
+  }
+}
+
+/// Rectangle struct with `f32` values, origin at the upper left.
+///
+/// SDL2 only draws with integer rects, so this is for doing your own sub-pixel
+/// math. Convert to a [Rect] with either
+/// [to_i_rounded](FRect::to_i_rounded) or
+/// [to_i_truncated](FRect::to_i_truncated) when it's time to draw.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[allow(missing_docs)]
+#[repr(C)]
+pub struct FRect {
+  pub x: f32,
+  pub y: f32,
+  pub w: f32,
+  pub h: f32,
+}
+impl FRect {
+  /// Converts to a `Rect`, rounding each value to the nearest integer.
+  ///
+  /// Halfway values round away from zero.
+  pub fn to_i_rounded(&self) -> Rect {
+    Rect {
+      x: self.x.round() as i32,
+      y: self.y.round() as i32,
+      w: self.w.round() as i32,
+      h: self.h.round() as i32,
+    }
+  }
+
+  /// Converts to a `Rect`, dropping the fractional part of each value.
+  ///
+  /// This goes toward zero, so negative positions move right / down.
+  pub fn to_i_truncated(&self) -> Rect {
+    Rect {
+      x: self.x as i32,
+      y: self.y as i32,
+      w: self.w as i32,
+      h: self.h as i32,
+    }
+  }
+}
+
+/// Basic struct for 2D positions with `f32` values.
+///
+/// See [FRect] for why you'd want this.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[allow(missing_docs)]
+#[repr(C)]
+pub struct FPoint {
+  pub x: f32,
+  pub y: f32,
+}
+impl FPoint {
+  /// Converts to a `Point`, rounding each value to the nearest integer.
+  ///
+  /// Halfway values round away from zero.
+  pub fn to_i_rounded(&self) -> Point {
+    Point {
+      x: self.x.round() as i32,
+      y: self.y.round() as i32,
+    }
+  }
+
+  /// Converts to a `Point`, dropping the fractional part of each value.
+  pub fn to_i_truncated(&self) -> Point {
+    Point {
+      x: self.x as i32,
+      y: self.y as i32,
+    }
+  }
+}
+impl Point {
+  /// Converts to an `FPoint`.
+  pub fn to_f(&self) -> FPoint {
+    FPoint {
+      x: self.x as f32,
+      y: self.y as f32,
+    }
+  }
+}
@@ -13,12 +13,267 @@ impl RendererFlags {
   phantom_fields! {
     self.0: SDL_RendererFlags_Type,
     accelerated: SDL_RENDERER_ACCELERATED,
+    /// Sync the `present` calls with the display's refresh rate.
+    ///
+    /// This can only be picked when the renderer is created. To change it you
+    /// have to make a new renderer, which also means re-creating all of your
+    /// textures.
     present_vsync: SDL_RENDERER_PRESENTVSYNC,
     software: SDL_RENDERER_SOFTWARE,
     target_texture: SDL_RENDERER_TARGETTEXTURE,
   }
 }
 
+/// Which way a gradient runs.
+///
+/// See [fill_rect_gradient](Renderer::fill_rect_gradient).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradientDirection {
+  /// Top to bottom.
+  Vertical,
+  /// Left to right.
+  Horizontal,
+}
+
+/// Information about a renderer.
+///
+/// See [info](Renderer::info).
+#[derive(Debug, Clone)]
+pub struct RendererInfo {
+  /// The name of the rendering driver.
+  pub name: String,
+  /// The abilities of the renderer.
+  pub flags: RendererFlags,
+  /// The texture formats that the renderer supports.
+  pub texture_formats: Vec<PixelFormatEnum>,
+  /// The maximum texture width (0 if there's no limit).
+  pub max_texture_width: i32,
+  /// The maximum texture height (0 if there's no limit).
+  pub max_texture_height: i32,
+}
+impl From<SDL_RendererInfo> for RendererInfo {
+  fn from(info: SDL_RendererInfo) -> Self {
+    let format_count = (info.num_texture_formats as usize).min(info.texture_formats.len());
+    Self {
+      name: if info.name.is_null() {
+        String::new()
+      } else {
+        unsafe { gather_string(info.name) }
+      },
+      flags: RendererFlags(info.flags as SDL_RendererFlags::Type),
+      texture_formats: info.texture_formats[..format_count]
+        .iter()
+        .map(|&f| PixelFormatEnum::from(f))
+        .collect(),
+      max_texture_width: info.max_texture_width,
+      max_texture_height: info.max_texture_height,
+    }
+  }
+}
+
+impl SDLToken {
+  /// Gets the info for each render driver that SDL2 was built with.
+  ///
+  /// These are in driver index order, so the position of a driver in this
+  /// list is the `driver_index` to give to
+  /// [create_renderer](Window::create_renderer). Not every driver listed will
+  /// necessarily work on the current system.
+  pub fn render_drivers(&self) -> Result<Vec<RendererInfo>, String> {
+    let count = unsafe { SDL_GetNumRenderDrivers() };
+    if count < 0 {
+      return Err(get_error());
+    }
+    (0..count)
+      .map(|index| {
+        let mut info = SDL_RendererInfo::default();
+        let out = unsafe { SDL_GetRenderDriverInfo(index, &mut info) };
+        if out == 0 {
+          Ok(RendererInfo::from(info))
+        } else {
+          Err(get_error())
+        }
+      })
+      .collect()
+  }
+}
+
+/// How a texture should be mirrored when it's drawn.
+///
+/// See [copy_ex](Renderer::copy_ex).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(windows, repr(i32))]
+#[cfg_attr(not(windows), repr(u32))]
+pub enum Flip {
+  /// Not flipped.
+  None = SDL_FLIP_NONE,
+  /// Flipped left to right.
+  Horizontal = SDL_FLIP_HORIZONTAL,
+  /// Flipped top to bottom.
+  Vertical = SDL_FLIP_VERTICAL,
+  /// Flipped both ways (the same as a half turn).
+  Both = SDL_FLIP_HORIZONTAL | SDL_FLIP_VERTICAL,
+}
+
+/// An opaque value for the renderer's current render target.
+///
+/// See [render_target](Renderer::render_target). This doesn't own the
+/// texture, it's just so that you can put the target back how it was.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct RawTexturePtr(pub(crate) *mut SDL_Texture);
+
+/// Puts the previous render target back when dropped.
+///
+/// See [push_render_target](Renderer::push_render_target) and
+/// [push](RenderTargetGuard::push).
+#[derive(Debug)]
+pub struct RenderTargetGuard<'a> {
+  renderer: *mut SDL_Renderer,
+  target: *mut SDL_Texture,
+  /// Either null (the window) or the target of an outer guard, which is kept
+  /// alive by the `'a` borrow of that guard.
+  previous: *mut SDL_Texture,
+  _marker: PhantomData<&'a SDL_Texture>,
+}
+impl<'a> RenderTargetGuard<'a> {
+  /// Sets a new render target until the nested guard is dropped, and then
+  /// puts this guard's target back.
+  ///
+  /// This guard is borrowed until then, which is what proves that its target
+  /// is still alive to be put back.
+  pub fn push<'b>(&'b mut self, target: &'b Texture) -> Result<RenderTargetGuard<'b>, String> {
+    let out = unsafe { SDL_SetRenderTarget(self.renderer, target.ptr) };
+    if out == 0 {
+      Ok(RenderTargetGuard {
+        renderer: self.renderer,
+        target: target.ptr,
+        previous: self.target,
+        _marker: PhantomData,
+      })
+    } else {
+      Err(get_error())
+    }
+  }
+}
+impl<'a> Drop for RenderTargetGuard<'a> {
+  fn drop(&mut self) {
+    unsafe { SDL_SetRenderTarget(self.renderer, self.previous) };
+  }
+}
+
+/// How a logical size is fit into the renderer's output.
+///
+/// See [set_logical_presentation](Renderer::set_logical_presentation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogicalPresentation {
+  /// Fill the whole output, even if that distorts the aspect ratio.
+  Stretch,
+  /// Scale as much as possible while keeping the aspect ratio, with black
+  /// bars on the sides (or top and bottom) that don't fit.
+  Letterbox,
+  /// Like `Letterbox`, but the scale is always a whole number, so every
+  /// logical pixel is the same size.
+  IntegerScale,
+}
+
+/// How a texture is filtered when it's drawn at a different size.
+///
+/// See [create_texture_from_surface_with_scale](Renderer::create_texture_from_surface_with_scale).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaleMode {
+  /// Nearest pixel sampling (sharp, blocky).
+  Nearest,
+  /// Linear filtering (smooth).
+  Linear,
+  /// Anisotropic filtering, where supported (otherwise the same as `Linear`).
+  Best,
+}
+impl ScaleMode {
+  /// The value of the `SDL_RENDER_SCALE_QUALITY` hint for this mode.
+  fn hint_value(self) -> &'static [u8] {
+    match self {
+      ScaleMode::Nearest => b"0\0",
+      ScaleMode::Linear => b"1\0",
+      ScaleMode::Best => b"2\0",
+    }
+  }
+}
+
+/// A generated image for [create_test_texture](Renderer::create_test_texture).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestPattern {
+  /// Every pixel is the same color.
+  SolidColor(Color),
+  /// `size` by `size` squares of alternating colors, starting with `a` in the
+  /// upper left.
+  Checkerboard {
+    /// The width and height of each square, in pixels.
+    size: i32,
+    /// The color of the upper left square.
+    a: Color,
+    /// The other color.
+    b: Color,
+  },
+  /// A left to right blend between two colors.
+  Gradient {
+    /// The color of the leftmost column.
+    from: Color,
+    /// The color of the rightmost column.
+    to: Color,
+  },
+}
+
+/// Makes the `RGBA32` pixels of a test pattern, in tightly packed rows.
+fn test_pattern_pixels(w: i32, h: i32, pattern: TestPattern) -> Vec<u8> {
+  let lerp =
+    |a: u8, b: u8, t: f32| (f32::from(a) + (f32::from(b) - f32::from(a)) * t).round() as u8;
+  let mut out = Vec::with_capacity(w as usize * h as usize * 4);
+  for y in 0..h {
+    for x in 0..w {
+      let c = match pattern {
+        TestPattern::SolidColor(c) => c,
+        TestPattern::Checkerboard { size, a, b } => {
+          if ((x / size) + (y / size)) % 2 == 0 {
+            a
+          } else {
+            b
+          }
+        }
+        TestPattern::Gradient { from, to } => {
+          let t = if w > 1 {
+            x as f32 / (w - 1) as f32
+          } else {
+            0.0
+          };
+          Color {
+            r: lerp(from.r, to.r, t),
+            g: lerp(from.g, to.g, t),
+            b: lerp(from.b, to.b, t),
+            a: lerp(from.a, to.a, t),
+          }
+        }
+      };
+      out.extend_from_slice(&[c.r, c.g, c.b, c.a]);
+    }
+  }
+  out
+}
+#[test]
+fn test_test_pattern_pixels() {
+  let solid = test_pattern_pixels(2, 1, TestPattern::SolidColor(Color::RED));
+  assert_eq!(solid, vec![255, 0, 0, 255, 255, 0, 0, 255]);
+  let (a, b) = (Color::WHITE, Color::BLACK);
+  let board = test_pattern_pixels(4, 2, TestPattern::Checkerboard { size: 2, a, b });
+  let first_column: Vec<u8> = board.chunks(4).map(|p| p[0]).collect();
+  assert_eq!(first_column, vec![255, 255, 0, 0, 255, 255, 0, 0]);
+  let (from, to) = (Color::BLACK, Color::WHITE);
+  let gradient = test_pattern_pixels(3, 1, TestPattern::Gradient { from, to });
+  assert_eq!(
+    gradient,
+    vec![0, 0, 0, 255, 128, 128, 128, 255, 255, 255, 255, 255]
+  );
+}
+
 /// Handle to some SDL2 rendering state.
 ///
 /// Helps you do things like upload data to the GPU and blit image data around.
@@ -30,9 +285,11 @@ impl RendererFlags {
 /// APIs. They both expect to have full control of the pixel process. Use this
 /// _or_ a hardware API.
 #[derive(Debug)]
-#[repr(transparent)]
 pub struct Renderer<'sdl, 'win> {
   pub(crate) ptr: *mut SDL_Renderer,
+  pub(crate) cached_output_size: Cell<(i32, i32)>,
+  pub(crate) draw_color_stack: RefCell<Vec<Color>>,
+  pub(crate) stretch_size: Cell<Option<(i32, i32)>>,
   pub(crate) _marker: PhantomData<&'win Window<'sdl>>,
 }
 impl<'sdl, 'win> Drop for Renderer<'sdl, 'win> {
@@ -41,25 +298,409 @@ impl<'sdl, 'win> Drop for Renderer<'sdl, 'win> {
   }
 }
 impl<'sdl, 'win> Renderer<'sdl, 'win> {
+  /// Makes a software renderer that draws into a surface instead of a window.
+  ///
+  /// All the usual drawing methods work, and the results go straight into
+  /// the surface's pixels (there's no need to `present`). This is handy for
+  /// headless rendering, such as checking drawing code in tests without
+  /// opening a window.
+  ///
+  /// The renderer keeps the surface borrowed for as long as it's alive.
+  pub fn from_surface(surface: &'win Surface<'sdl>) -> Result<Self, String> {
+    let ptr = unsafe { SDL_CreateSoftwareRenderer(surface.ptr) };
+    if ptr.is_null() {
+      Err(get_error())
+    } else {
+      // Note: the marker says "window", but it's only there for the lifetime,
+      // and here that's the surface borrow.
+      let renderer = Renderer {
+        ptr,
+        cached_output_size: Cell::new((0, 0)),
+        draw_color_stack: RefCell::new(Vec::new()),
+        stretch_size: Cell::new(None),
+        _marker: PhantomData,
+      };
+      renderer.on_resize()?;
+      Ok(renderer)
+    }
+  }
+
+  /// Gets the raw `*mut SDL_Renderer` pointer, for use with other C libraries.
+  ///
+  /// The renderer is still owned by this value: don't free the pointer, and don't
+  /// use it after this value has been dropped.
+  pub fn as_raw(&self) -> *mut SDL_Renderer {
+    self.ptr
+  }
+
   /// Makes a texture with the contents of the surface specified.
   ///
   /// The TextureAccess hint for textures from this is "static".
   ///
   /// The pixel format might be different from the surface's pixel format.
+  /// Check [format](Texture::format) on the texture to see what was picked,
+  /// and see [preferred_texture_format](Renderer::preferred_texture_format)
+  /// for avoiding a conversion.
+  ///
+  /// If the surface has a color key, the texture gets an alpha channel but
+  /// its blend mode isn't changed, so see
+  /// [create_texture_from_surface_keyed](Renderer::create_texture_from_surface_keyed)
+  /// if you want the keyed pixels to actually draw as transparent.
   pub fn create_texture_from_surface<'ren>(
     &'ren self, surf: &Surface,
   ) -> Result<Texture<'sdl, 'win, 'ren>, String> {
-    let ptr: *mut SDL_Texture = unsafe { SDL_CreateTextureFromSurface(self.ptr, surf.ptr) };
+    Texture::from_ptr(unsafe { SDL_CreateTextureFromSurface(self.ptr, surf.ptr) })
+  }
+
+  /// Makes a texture from the surface, with `key` as the transparent color.
+  ///
+  /// With a color key, SDL2 turns the keyed pixels into fully transparent
+  /// pixels as it converts the surface to the texture's format, which is why
+  /// the texture always ends up with an alpha channel. However, the texture
+  /// will only _draw_ as transparent if its blend mode is `Blend`, so that's
+  /// set here too. The key is matched after being mapped into the surface's
+  /// pixel format, so with low bit depth formats several nearby colors can
+  /// count as the key.
+  ///
+  /// The surface itself isn't changed, the key is set on a copy.
+  pub fn create_texture_from_surface_keyed<'ren>(
+    &'ren self, surf: &Surface, key: Color,
+  ) -> Result<Texture<'sdl, 'win, 'ren>, String> {
+    let mut keyed = surf.duplicate()?;
+    keyed.set_color_key(Some(key))?;
+    let texture = self.create_texture_from_surface(&keyed)?;
+    texture.set_blend_mode(BlendMode::Blend)?;
+    Ok(texture)
+  }
+
+  /// The renderer's preferred texture format.
+  ///
+  /// This is the first format listed in the renderer's
+  /// [info](Renderer::info). Textures made from a surface in this format don't
+  /// need a conversion during upload.
+  pub fn preferred_texture_format(&self) -> Result<PixelFormatEnum, String> {
+    let info = self.info()?;
+    match info.texture_formats.first() {
+      Some(&format) => Ok(format),
+      None => Err("beryllium error: the renderer has no texture formats.".to_string()),
+    }
+  }
+
+  /// Makes a copy of the surface in the renderer's
+  /// [preferred_texture_format](Renderer::preferred_texture_format).
+  ///
+  /// If you'll upload the same surface many times it's worth converting it
+  /// once up front.
+  pub fn convert_surface_to_native(&self, surf: &Surface<'sdl>) -> Result<Surface<'sdl>, String> {
+    surf.convert_format(self.preferred_texture_format()?)
+  }
+
+  /// Makes a texture from the surface, using a particular scale mode.
+  ///
+  /// SDL2's `SDL_RENDER_SCALE_QUALITY` hint is global, and it's read when a
+  /// texture is _made_, not when it's drawn. So setting it after loading your
+  /// textures does nothing to them, which surprises just about everyone. This
+  /// sets the hint to `mode`, makes the texture, and then restores whatever
+  /// the hint was before, so you get the mode you asked for regardless of when
+  /// the hint gets set elsewhere.
+  ///
+  /// If the hint has been set with override priority (for example, by an
+  /// environment variable) it can't be changed, and you get an error.
+  pub fn create_texture_from_surface_with_scale<'ren>(
+    &'ren self, surf: &Surface, mode: ScaleMode,
+  ) -> Result<Texture<'sdl, 'win, 'ren>, String> {
+    let name = SDL_HINT_RENDER_SCALE_QUALITY.as_ptr() as *const c_char;
+    let old_ptr = unsafe { SDL_GetHint(name) };
+    let old: Option<Vec<u8>> = if old_ptr.is_null() {
+      None
+    } else {
+      Some(
+        unsafe { gather_string(old_ptr) }
+          .bytes()
+          .chain(Some(0))
+          .collect(),
+      )
+    };
+    let value = mode.hint_value().as_ptr() as *const c_char;
+    if unsafe { SDL_SetHint(name, value) } != SDL_TRUE {
+      return Err("beryllium error: the scale quality hint is overridden.".to_string());
+    }
+    let out = self.create_texture_from_surface(surf);
+    match old {
+      Some(old) => unsafe {
+        SDL_SetHint(name, old.as_ptr() as *const c_char);
+      },
+      None => {
+        // Note: a null value puts the hint back to unset, as long as the SDL2
+        // that we're running with is new enough to accept a null. Older ones
+        // refuse it, and have no other way to unset a hint, so then it goes
+        // to "0", which they treat the same as unset.
+        if unsafe { SDL_SetHint(name, null()) } != SDL_TRUE {
+          unsafe { SDL_SetHint(name, b"0\0".as_ptr() as *const c_char) };
+        }
+      }
+    }
+    out
+  }
+
+  /// Gets information about the renderer.
+  ///
+  /// The flags here are what the renderer actually is, which might not be
+  /// what you asked for when you created it.
+  pub fn info(&self) -> Result<RendererInfo, String> {
+    let mut info = SDL_RendererInfo::default();
+    let out = unsafe { SDL_GetRendererInfo(self.ptr, &mut info) };
+    if out == 0 {
+      Ok(RendererInfo::from(info))
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Checks if the renderer is using hardware acceleration.
+  ///
+  /// SDL2 can fall back to a software renderer even if you asked for an
+  /// accelerated one, so this is how you find out what you really got.
+  pub fn is_accelerated(&self) -> Result<bool, String> {
+    self.info().map(|info| info.flags.accelerated())
+  }
+
+  /// Checks if the renderer is a software renderer.
+  pub fn is_software(&self) -> Result<bool, String> {
+    self.info().map(|info| info.flags.software())
+  }
+
+  /// Makes a new texture with uninitialized contents.
+  ///
+  /// The `access` value determines what you can do with the texture later:
+  /// only `Streaming` textures can be locked, and only `Target` textures can
+  /// be rendered into.
+  pub fn create_texture<'ren>(
+    &'ren self, format: PixelFormatEnum, access: TextureAccess, w: i32, h: i32,
+  ) -> Result<Texture<'sdl, 'win, 'ren>, String> {
+    Texture::from_ptr(unsafe { SDL_CreateTexture(self.ptr, format as u32, access as i32, w, h) })
+  }
+
+  /// Makes a texture filled with a generated test pattern.
+  ///
+  /// This is handy for getting something on the screen before you have any
+  /// art, and a [Checkerboard](TestPattern::Checkerboard) or
+  /// [Gradient](TestPattern::Gradient) makes it easy to see if a texture is
+  /// being stretched or flipped. The texture is static, in the `RGBA32`
+  /// format.
+  pub fn create_test_texture<'ren>(
+    &'ren self, width: i32, height: i32, pattern: TestPattern,
+  ) -> Result<Texture<'sdl, 'win, 'ren>, String> {
+    if width <= 0 || height <= 0 {
+      return Err("beryllium error: the texture size must be positive.".to_string());
+    }
+    if let TestPattern::Checkerboard { size, .. } = pattern {
+      if size <= 0 {
+        return Err("beryllium error: the checkerboard size must be positive.".to_string());
+      }
+    }
+    let texture = self.create_texture(
+      PixelFormatEnum::RGBA32,
+      TextureAccess::Static,
+      width,
+      height,
+    )?;
+    let pixels = test_pattern_pixels(width, height, pattern);
+    texture.update(None, &pixels, width * 4)?;
+    Ok(texture)
+  }
+
+  /// Makes a render target texture that's the same size as the output.
+  ///
+  /// The texture uses the `RGBA8888` format. Since the size is only checked
+  /// when the texture is made you'll have to make a new one whenever the
+  /// window is resized.
+  pub fn create_target_texture_matching_output<'ren>(
+    &'ren self,
+  ) -> Result<Texture<'sdl, 'win, 'ren>, String> {
+    let (w, h) = self.output_size()?;
+    self.create_texture(PixelFormatEnum::RGBA8888, TextureAccess::Target, w, h)
+  }
+
+  /// Makes a [Framebuffer] for CPU side drawing.
+  ///
+  /// Both the surface and the texture use the `RGBA8888` format.
+  pub fn create_framebuffer<'ren>(
+    &'ren self, w: i32, h: i32,
+  ) -> Result<Framebuffer<'sdl, 'win, 'ren>, String> {
+    let format = PixelFormatEnum::RGBA8888;
+    let texture = self.create_texture(format, TextureAccess::Streaming, w, h)?;
+    let depth = format.bits_per_pixel() as i32;
+    let ptr = unsafe { SDL_CreateRGBSurfaceWithFormat(0, w, h, depth, format as u32) };
     if ptr.is_null() {
       Err(get_error())
     } else {
-      Ok(Texture {
-        ptr,
-        _marker: PhantomData,
+      Ok(Framebuffer {
+        texture,
+        surface: Surface {
+          ptr,
+          _marker: PhantomData,
+        },
       })
     }
   }
 
+  /// Gets the output size of the renderer, in physical pixels.
+  ///
+  /// This can differ from the window's [size](Window::size) when the window
+  /// is high-DPI.
+  pub fn output_size(&self) -> Result<(i32, i32), String> {
+    let mut w = 0;
+    let mut h = 0;
+    let out = unsafe { SDL_GetRendererOutputSize(self.ptr, &mut w, &mut h) };
+    if out == 0 {
+      Ok((w, h))
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Re-reads the renderer's output size into the cache.
+  ///
+  /// Call this whenever you get a [WindowEvent::SizeChanged] for the
+  /// renderer's window, and then make any other size dependent updates (such
+  /// as render target textures) using
+  /// [cached_output_size](Renderer::cached_output_size).
+  ///
+  /// This also updates the scale of a `Stretch`
+  /// [logical presentation](Renderer::set_logical_presentation).
+  pub fn on_resize(&self) -> Result<(), String> {
+    let size = self.output_size()?;
+    self.cached_output_size.set(size);
+    self.apply_stretch()
+  }
+
+  /// Sets a device independent resolution for rendering.
+  ///
+  /// After this you can draw as if the output was `width` by `height`, and
+  /// it's scaled up (or down) to the real output according to `mode`.
+  ///
+  /// * `Letterbox` and `IntegerScale` use SDL2's own logical size support, so
+  ///   SDL2 also converts mouse event positions into logical coordinates for
+  ///   you.
+  /// * `Stretch` is done with the render scale instead, since SDL2's logical
+  ///   size always keeps the aspect ratio. Mouse events stay in window pixels,
+  ///   and the scale is updated in [on_resize](Renderer::on_resize), so be sure
+  ///   to call that when the window changes size.
+  ///
+  /// Use [logical_to_window](Renderer::logical_to_window) to go from a
+  /// logical position to a window position in any mode.
+  pub fn set_logical_presentation(
+    &self, width: i32, height: i32, mode: LogicalPresentation,
+  ) -> Result<(), String> {
+    if width <= 0 || height <= 0 {
+      return Err("beryllium error: logical size must be positive.".to_string());
+    }
+    let (w, h, integer) = match mode {
+      LogicalPresentation::Stretch => (0, 0, SDL_FALSE),
+      LogicalPresentation::Letterbox => (width, height, SDL_FALSE),
+      LogicalPresentation::IntegerScale => (width, height, SDL_TRUE),
+    };
+    // Note: a logical size of 0x0 clears any old logical size, and also resets
+    // the scale back to 1.0 and the viewport to the full output.
+    let out = unsafe { SDL_RenderSetLogicalSize(self.ptr, w, h) };
+    if out != 0 {
+      return Err(get_error());
+    }
+    let out = unsafe { SDL_RenderSetIntegerScale(self.ptr, integer) };
+    if out != 0 {
+      return Err(get_error());
+    }
+    if mode == LogicalPresentation::Stretch {
+      self.stretch_size.set(Some((width, height)));
+      self.apply_stretch()
+    } else {
+      self.stretch_size.set(None);
+      Ok(())
+    }
+  }
+
+  /// Converts a logical position into a position in the renderer's output.
+  ///
+  /// This accounts for the scale and the letterbox offset of the current
+  /// [logical presentation](Renderer::set_logical_presentation).
+  pub fn logical_to_window(&self, p: Point) -> Point {
+    let (scale_x, scale_y, offset_x, offset_y) = self.logical_mapping();
+    Point {
+      x: (p.x as f32 * scale_x + offset_x).round() as i32,
+      y: (p.y as f32 * scale_y + offset_y).round() as i32,
+    }
+  }
+
+  /// Converts a position in the renderer's output (such as a mouse position)
+  /// into logical coordinates.
+  ///
+  /// This undoes the render scale and viewport offset, whether they come from
+  /// [set_logical_presentation](Renderer::set_logical_presentation) or from
+  /// setting them yourself.
+  ///
+  /// With a `Letterbox` or `IntegerScale` presentation SDL2 has already
+  /// converted mouse events to logical coordinates, so don't convert those a
+  /// second time.
+  pub fn window_to_logical(&self, x: f32, y: f32) -> (f32, f32) {
+    let (scale_x, scale_y, offset_x, offset_y) = self.logical_mapping();
+    ((x - offset_x) / scale_x, (y - offset_y) / scale_y)
+  }
+
+  /// Converts a logical position into a position in the renderer's output.
+  ///
+  /// The inverse of [window_to_logical](Renderer::window_to_logical). This is
+  /// the floating point version of
+  /// [logical_to_window](Renderer::logical_to_window), with no rounding.
+  pub fn logical_to_window_f(&self, x: f32, y: f32) -> (f32, f32) {
+    let (scale_x, scale_y, offset_x, offset_y) = self.logical_mapping();
+    (x * scale_x + offset_x, y * scale_y + offset_y)
+  }
+
+  /// Gets `(scale_x, scale_y, offset_x, offset_y)` so that an output position
+  /// is `logical * scale + offset`.
+  fn logical_mapping(&self) -> (f32, f32, f32, f32) {
+    let mut scale_x = 1.0;
+    let mut scale_y = 1.0;
+    let mut viewport = Rect::default();
+    unsafe {
+      SDL_RenderGetScale(self.ptr, &mut scale_x, &mut scale_y);
+      SDL_RenderGetViewport(self.ptr, &mut viewport as *mut Rect as *mut SDL_Rect);
+    }
+    // Note: the viewport is stored in logical units, so the offset is scaled.
+    (
+      scale_x,
+      scale_y,
+      viewport.x as f32 * scale_x,
+      viewport.y as f32 * scale_y,
+    )
+  }
+
+  /// Updates the render scale for a `Stretch` logical presentation, if there
+  /// is one.
+  fn apply_stretch(&self) -> Result<(), String> {
+    if let Some((width, height)) = self.stretch_size.get() {
+      let (out_w, out_h) = self.cached_output_size.get();
+      let scale_x = out_w as f32 / width as f32;
+      let scale_y = out_h as f32 / height as f32;
+      let out = unsafe { SDL_RenderSetScale(self.ptr, scale_x, scale_y) };
+      if out != 0 {
+        return Err(get_error());
+      }
+    }
+    Ok(())
+  }
+
+  /// The output size as of the last [on_resize](Renderer::on_resize) call.
+  ///
+  /// This is read when the renderer is created, so it's only stale if the
+  /// window has been resized since then and you didn't call `on_resize`. This
+  /// saves a call into SDL2, but mostly it gives you a single, consistent
+  /// size to do all of your layout with each frame.
+  pub fn cached_output_size(&self) -> (i32, i32) {
+    self.cached_output_size.get()
+  }
+
   /// Obtains the current draw color.
   pub fn draw_color(&self) -> Result<Color, String> {
     let mut color = Color::default();
@@ -89,6 +730,83 @@ impl<'sdl, 'win> Renderer<'sdl, 'win> {
     }
   }
 
+  /// Runs the closure with the draw color set to `color`, and then puts the
+  /// old draw color back.
+  ///
+  /// The old color is restored even if the closure gives an error.
+  pub fn with_draw_color<F, R>(&self, color: Color, f: F) -> Result<R, String>
+  where
+    F: FnOnce(&Self) -> Result<R, String>,
+  {
+    let old_color = self.draw_color()?;
+    self.set_draw_color(color)?;
+    let out = f(self);
+    let restore = self.set_draw_color(old_color);
+    let out = out?;
+    restore.map(|_| out)
+  }
+
+  /// Obtains the blend mode used for drawing operations.
+  pub fn draw_blend_mode(&self) -> Result<BlendMode, String> {
+    let mut mode = SDL_BLENDMODE_NONE;
+    let out = unsafe { SDL_GetRenderDrawBlendMode(self.ptr, &mut mode) };
+    if out == 0 {
+      Ok(BlendMode::from(mode))
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Assigns the blend mode used for drawing operations (fill, line, etc).
+  ///
+  /// Note that `BlendMode::Invalid` isn't a mode you can actually set.
+  pub fn set_draw_blend_mode(&self, mode: BlendMode) -> Result<(), String> {
+    let out = unsafe { SDL_SetRenderDrawBlendMode(self.ptr, mode as SDL_BlendMode::Type) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Clears the render target to fully transparent black.
+  ///
+  /// This is mostly for target textures with an alpha channel. The blend mode
+  /// is set to `None` and the draw color to `(0, 0, 0, 0)` for the clear, so
+  /// the old contents are replaced rather than blended with. Both settings
+  /// are restored afterwards.
+  pub fn clear_transparent(&self) -> Result<(), String> {
+    let old_mode = self.draw_blend_mode()?;
+    self.set_draw_blend_mode(BlendMode::None)?;
+    let out = self.with_draw_color(Color::default(), |r| r.clear());
+    let restore = self.set_draw_blend_mode(old_mode);
+    out.and(restore)
+  }
+
+  /// Saves the current draw color on a stack and then sets the new color.
+  ///
+  /// Use [pop_draw_color](Renderer::pop_draw_color) to go back to the saved
+  /// color. This is like [with_draw_color](Renderer::with_draw_color), but
+  /// the push and pop don't have to happen in the same function.
+  pub fn push_draw_color(&self, color: Color) -> Result<(), String> {
+    let old_color = self.draw_color()?;
+    self.set_draw_color(color)?;
+    self.draw_color_stack.borrow_mut().push(old_color);
+    Ok(())
+  }
+
+  /// Sets the draw color back to the color saved by the last
+  /// [push_draw_color](Renderer::push_draw_color).
+  ///
+  /// You get an error if there's no saved color.
+  pub fn pop_draw_color(&self) -> Result<(), String> {
+    let old_color = self.draw_color_stack.borrow_mut().pop();
+    match old_color {
+      Some(color) => self.set_draw_color(color),
+      None => Err("beryllium error: the draw color stack is empty.".to_string()),
+    }
+  }
+
   /// Clears the render target with the current draw color.
   pub fn clear(&self) -> Result<(), String> {
     if unsafe { SDL_RenderClear(self.ptr) } == 0 {
@@ -108,6 +826,16 @@ impl<'sdl, 'win> Renderer<'sdl, 'win> {
     }
   }
 
+  /// Draws a horizontal line from `x1` to `x2` (inclusive) along row `y`.
+  pub fn draw_hline(&self, x1: i32, x2: i32, y: i32) -> Result<(), String> {
+    self.draw_line(x1, y, x2, y)
+  }
+
+  /// Draws a vertical line from `y1` to `y2` (inclusive) along column `x`.
+  pub fn draw_vline(&self, x: i32, y1: i32, y2: i32) -> Result<(), String> {
+    self.draw_line(x, y1, x, y2)
+  }
+
   /// Using the slice of `n` points, draws `n-1` lines end to end.
   pub fn draw_lines(&self, points: &[Point]) -> Result<(), String> {
     if points.len() > core::i32::MAX as usize {
@@ -123,6 +851,221 @@ impl<'sdl, 'win> Renderer<'sdl, 'win> {
     }
   }
 
+  /// Draws the outline of a polygon.
+  ///
+  /// This is like [`draw_lines`](Renderer::draw_lines), but there's also a
+  /// final line from the last point back to the first point, so you don't
+  /// have to append the closing point yourself. An empty slice draws nothing.
+  pub fn draw_polygon(&self, points: &[Point]) -> Result<(), String> {
+    // Note: the closing point is appended, so we need room for one more.
+    if points.len() >= i32::MAX as usize {
+      return Err("beryllium error: len cannot exceed `i32::MAX`.".to_string());
+    }
+    let first = match points.first() {
+      Some(first) => *first,
+      None => return Ok(()),
+    };
+    let mut closed = Vec::with_capacity(points.len() + 1);
+    closed.extend_from_slice(points);
+    closed.push(first);
+    let ptr = closed.as_ptr() as *const SDL_Point;
+    let count = closed.len() as i32;
+    let out = unsafe { SDL_RenderDrawLines(self.ptr, ptr, count) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Draws many separate line segments with as few calls into SDL2 as
+  /// possible.
+  ///
+  /// Segments that continue on from the end of the previous segment are
+  /// joined up into a single polyline, and each polyline is one
+  /// `SDL_RenderDrawLines` call. So if you order the segments so that they
+  /// connect (such as walking a grid in a zig-zag), you get very few calls.
+  /// Segments that don't connect still work, they just start a new polyline.
+  pub fn draw_line_batch(&self, segments: &[(Point, Point)]) -> Result<(), String> {
+    let mut polyline: Vec<Point> = Vec::with_capacity(segments.len() + 1);
+    for &(start, end) in segments {
+      if polyline.last() != Some(&start) {
+        if !polyline.is_empty() {
+          self.draw_lines(&polyline)?;
+          polyline.clear();
+        }
+        polyline.push(start);
+      }
+      polyline.push(end);
+    }
+    if !polyline.is_empty() {
+      self.draw_lines(&polyline)?;
+    }
+    Ok(())
+  }
+
+  /// Draws all the points in the slice.
+  pub fn draw_points(&self, points: &[Point]) -> Result<(), String> {
+    if points.len() > i32::MAX as usize {
+      return Err("beryllium error: len cannot exceed `i32::MAX`.".to_string());
+    }
+    let ptr = points.as_ptr() as *const SDL_Point;
+    let count = points.len() as i32;
+    let out = unsafe { SDL_RenderDrawPoints(self.ptr, ptr, count) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Fills all the rects in the slice with the current draw color.
+  pub fn fill_rects(&self, rects: &[Rect]) -> Result<(), String> {
+    if rects.len() > i32::MAX as usize {
+      return Err("beryllium error: len cannot exceed `i32::MAX`.".to_string());
+    }
+    let ptr = rects.as_ptr() as *const SDL_Rect;
+    let count = rects.len() as i32;
+    let out = unsafe { SDL_RenderFillRects(self.ptr, ptr, count) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Fills all the rects from an iterator with the current draw color.
+  ///
+  /// This is like [fill_rects](Renderer::fill_rects), but you can pass a
+  /// `map` or `filter` chain directly. Rects are gathered into a fixed size
+  /// buffer on the stack and submitted in batches, so there's no allocation.
+  pub fn fill_rects_iter<I: IntoIterator<Item = Rect>>(&self, rects: I) -> Result<(), String> {
+    const BATCH: usize = 64;
+    let mut buffer = [Rect::default(); BATCH];
+    let mut len = 0;
+    for rect in rects {
+      buffer[len] = rect;
+      len += 1;
+      if len == BATCH {
+        self.fill_rects(&buffer)?;
+        len = 0;
+      }
+    }
+    if len > 0 {
+      self.fill_rects(&buffer[..len])?;
+    }
+    Ok(())
+  }
+
+  /// Draws the outline of a circle.
+  ///
+  /// SDL2 has no circle primitive, so this uses the midpoint circle algorithm
+  /// to find the points and then draws them all in a single batch.
+  pub fn draw_circle(&self, center: Point, radius: i32) -> Result<(), String> {
+    if radius < 0 {
+      return Err("beryllium error: radius cannot be negative.".to_string());
+    }
+    let Point { x: cx, y: cy } = center;
+    let mut points: Vec<Point> = Vec::new();
+    for (x, y) in circle_octant(radius) {
+      let offsets = [
+        (x, y),
+        (y, x),
+        (-y, x),
+        (-x, y),
+        (-x, -y),
+        (-y, -x),
+        (y, -x),
+        (x, -y),
+      ];
+      points.extend(offsets.iter().map(|&(dx, dy)| Point {
+        x: cx + dx,
+        y: cy + dy,
+      }));
+    }
+    // Note: The octant edges produce duplicate points, and drawing a point
+    // twice would look wrong with a blend mode active.
+    points.sort_by_key(|p| (p.y, p.x));
+    points.dedup();
+    self.draw_points(&points)
+  }
+
+  /// Draws a filled circle.
+  ///
+  /// This is the same shape as [draw_circle](Renderer::draw_circle), filled in
+  /// with one horizontal scanline per row. The scanlines are all submitted as
+  /// a single batch of 1 pixel tall rects.
+  pub fn fill_circle(&self, center: Point, radius: i32) -> Result<(), String> {
+    if radius < 0 {
+      return Err("beryllium error: radius cannot be negative.".to_string());
+    }
+    let half_widths = circle_half_widths(radius);
+    let rects: Vec<Rect> = (-radius..=radius)
+      .map(|dy| {
+        let half_width = half_widths[dy.unsigned_abs() as usize];
+        Rect {
+          x: center.x - half_width,
+          y: center.y + dy,
+          w: 2 * half_width + 1,
+          h: 1,
+        }
+      })
+      .collect();
+    self.fill_rects(&rects)
+  }
+
+  /// Fills a polygon with the color given.
+  ///
+  /// The points are the polygon's vertices in order, and the last point
+  /// connects back to the first. Any polygon works (convex or not), using the
+  /// "even-odd" rule for which areas count as inside.
+  ///
+  /// The current draw color is restored afterwards.
+  ///
+  /// The polygon is scan converted on the CPU and then submitted as a single
+  /// batch of 1 pixel tall rects.
+  pub fn fill_polygon(&self, points: &[Point], color: Color) -> Result<(), String> {
+    let spans = polygon_spans(points);
+    self.with_draw_color(color, |r| r.fill_rects(&spans))
+  }
+
+  /// Fills a rect with a gradient from the `start` color to the `end` color.
+  ///
+  /// With `Vertical` the gradient goes from the top row to the bottom row, and
+  /// with `Horizontal` it goes from the left column to the right column.
+  ///
+  /// The current draw color is restored afterwards.
+  pub fn fill_rect_gradient(
+    &self, rect: Rect, start: Color, end: Color, direction: GradientDirection,
+  ) -> Result<(), String> {
+    if rect.w <= 0 || rect.h <= 0 {
+      return Ok(());
+    }
+    let steps = match direction {
+      GradientDirection::Vertical => rect.h,
+      GradientDirection::Horizontal => rect.w,
+    };
+    self.with_draw_color(start, |r| {
+      for i in 0..steps {
+        let line = match direction {
+          GradientDirection::Vertical => Rect {
+            y: rect.y + i,
+            h: 1,
+            ..rect
+          },
+          GradientDirection::Horizontal => Rect {
+            x: rect.x + i,
+            w: 1,
+            ..rect
+          },
+        };
+        r.set_draw_color(gradient_color(start, end, i, steps))?;
+        r.fill_rects(&[line])?;
+      }
+      Ok(())
+    })
+  }
+
   /// Blits the texture to the rendering target.
   ///
   /// * `src`: Optional clip rect of where to copy _from_. If None, the whole
@@ -145,6 +1088,210 @@ impl<'sdl, 'win> Renderer<'sdl, 'win> {
     }
   }
 
+  /// Blits the texture to the rendering target, with rotation and flipping.
+  ///
+  /// * `src` and `dst` work like with [copy](Renderer::copy).
+  /// * `angle` is the clockwise rotation in degrees, applied to `dst`.
+  /// * `center` is the point to rotate around, relative to the upper left of
+  ///   `dst`. If None, the center of `dst` is used.
+  /// * `flip` mirrors the image (before rotation).
+  pub fn copy_ex(
+    &self, t: &Texture, src: Option<Rect>, dst: Option<Rect>, angle: f64, center: Option<Point>,
+    flip: Flip,
+  ) -> Result<(), String> {
+    unsafe {
+      let src_ptr = core::mem::transmute::<Option<&Rect>, *const SDL_Rect>(src.as_ref());
+      let dst_ptr = core::mem::transmute::<Option<&Rect>, *const SDL_Rect>(dst.as_ref());
+      let center_ptr = core::mem::transmute::<Option<&Point>, *const SDL_Point>(center.as_ref());
+      let out = SDL_RenderCopyEx(
+        self.ptr,
+        t.ptr,
+        src_ptr,
+        dst_ptr,
+        angle,
+        center_ptr,
+        flip as SDL_RendererFlip::Type,
+      );
+      if out == 0 {
+        Ok(())
+      } else {
+        Err(get_error())
+      }
+    }
+  }
+
+  /// Like [copy_ex](Renderer::copy_ex), but with float values for the
+  /// destination and center.
+  ///
+  /// `dst` and `center` get rounded to the nearest pixel. The rotation itself
+  /// is still smooth, but the position snaps to whole pixels.
+  pub fn copy_ex_f(
+    &self, t: &Texture, src: Option<Rect>, dst: Option<FRect>, angle: f64, center: Option<FPoint>,
+    flip: Flip,
+  ) -> Result<(), String> {
+    self.copy_ex(
+      t,
+      src,
+      dst.map(|r| r.to_i_rounded()),
+      angle,
+      center.map(|p| p.to_i_rounded()),
+      flip,
+    )
+  }
+
+  /// Draws text using a grid of glyphs in an atlas texture.
+  ///
+  /// * `glyph_size` is the `(width, height)` of each glyph cell.
+  /// * `atlas_cols` is how many glyphs each row of the atlas holds.
+  ///
+  /// Glyph `n` of the atlas is used for byte value `n` (so you probably want
+  /// an atlas laid out in ASCII order). Each character moves right by one
+  /// glyph width, and a `'\n'` moves back to `dest.x` and down by one glyph
+  /// height. Non-ASCII characters take up a space but draw nothing.
+  pub fn draw_glyphs(
+    &self, atlas: &Texture, glyph_size: (i32, i32), atlas_cols: i32, text: &str, dest: Point,
+  ) -> Result<(), String> {
+    let (glyph_w, glyph_h) = glyph_size;
+    let mut pos = dest;
+    for ch in text.chars() {
+      if ch == '\n' {
+        pos.x = dest.x;
+        pos.y += glyph_h;
+        continue;
+      }
+      if ch.is_ascii() && atlas_cols > 0 {
+        let src = glyph_rect(ch as u8, glyph_size, atlas_cols);
+        let dst = Rect {
+          x: pos.x,
+          y: pos.y,
+          w: glyph_w,
+          h: glyph_h,
+        };
+        self.copy(atlas, Some(src), Some(dst))?;
+      }
+      pos.x += glyph_w;
+    }
+    Ok(())
+  }
+
+  /// Sets the texture that drawing goes to.
+  ///
+  /// * If `Some(texture)`, drawing goes to the texture, which must have been
+  ///   made with `TextureAccess::Target`.
+  /// * If `None`, drawing goes back to the window.
+  ///
+  /// The renderer must have been made with the `target_texture` flag.
+  pub fn set_render_target(&self, target: Option<&Texture>) -> Result<(), String> {
+    let ptr = target.map(|t| t.ptr).unwrap_or(null_mut());
+    let out = unsafe { SDL_SetRenderTarget(self.ptr, ptr) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets the texture that drawing currently goes to, or `None` if drawing
+  /// goes to the window.
+  ///
+  /// You can't do anything with the value except put the target back how it
+  /// was with [restore_render_target](Renderer::restore_render_target).
+  pub fn render_target(&self) -> Option<RawTexturePtr> {
+    let ptr = unsafe { SDL_GetRenderTarget(self.ptr) };
+    if ptr.is_null() {
+      None
+    } else {
+      Some(RawTexturePtr(ptr))
+    }
+  }
+
+  /// Sets the render target to a value from
+  /// [render_target](Renderer::render_target).
+  ///
+  /// # Safety
+  ///
+  /// * If `Some`, the texture the value came from must not have been dropped.
+  pub unsafe fn restore_render_target(&self, target: Option<RawTexturePtr>) -> Result<(), String> {
+    let ptr = target.map(|t| t.0).unwrap_or(null_mut());
+    let out = SDL_SetRenderTarget(self.ptr, ptr);
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the render target until the guard is dropped.
+  ///
+  /// Drawing goes to `target` (see
+  /// [set_render_target](Renderer::set_render_target)), and when the guard
+  /// drops drawing goes back to the window, even on an early return. The
+  /// renderer can still be used for drawing while the guard is alive.
+  ///
+  /// To nest targets use [push](RenderTargetGuard::push) on the outer guard,
+  /// which puts the outer guard's target back instead. A texture target that
+  /// you set by hand isn't put back, since nothing here can prove that the
+  /// texture is still alive when the guard drops.
+  pub fn push_render_target<'a>(
+    &'a self, target: &'a Texture,
+  ) -> Result<RenderTargetGuard<'a>, String> {
+    self.set_render_target(Some(target))?;
+    Ok(RenderTargetGuard {
+      renderer: self.ptr,
+      target: target.ptr,
+      previous: null_mut(),
+      _marker: PhantomData,
+    })
+  }
+
+  /// Destroys the renderer right now, consuming it.
+  ///
+  /// This is what dropping the renderer does too, but since `Drop` can't
+  /// report problems this gives an error if SDL2 sets one during the
+  /// cleanup.
+  pub fn destroy(self) -> Result<(), String> {
+    let this = ManuallyDrop::new(self);
+    // Note: the color stack has to be freed by hand since `Drop` won't run.
+    drop(core::mem::take(&mut *this.draw_color_stack.borrow_mut()));
+    clear_error();
+    unsafe { SDL_DestroyRenderer(this.ptr) };
+    let err = get_error();
+    if err.is_empty() {
+      Ok(())
+    } else {
+      Err(err)
+    }
+  }
+
+  /// Draws a sub-scene into a new texture.
+  ///
+  /// This makes a `width` by `height` render target texture (in the
+  /// `RGBA8888` format), clears it to transparent, and then runs `f` with
+  /// drawing going to the texture. Drawing goes back to the window
+  /// afterwards (as with
+  /// [push_render_target](Renderer::push_render_target)), even if `f` gives
+  /// an error, and then you get the texture.
+  ///
+  /// The texture's blend mode is set to `Blend`, so the parts that `f` didn't
+  /// draw over stay see-through when you copy the texture somewhere.
+  pub fn render_to_texture<'ren, F: FnOnce(&Renderer) -> Result<(), String>>(
+    &'ren self, width: i32, height: i32, f: F,
+  ) -> Result<Texture<'sdl, 'win, 'ren>, String> {
+    let texture = self.create_texture(
+      PixelFormatEnum::RGBA8888,
+      TextureAccess::Target,
+      width,
+      height,
+    )?;
+    {
+      let _guard = self.push_render_target(&texture)?;
+      self.clear_transparent()?;
+      f(self)?;
+    }
+    texture.set_blend_mode(BlendMode::Blend)?;
+    Ok(texture)
+  }
+
   /// Presents the backbuffer to the user.
   ///
   /// After a present, all backbuffer data should be assumed to be invalid, and
@@ -153,4 +1300,166 @@ impl<'sdl, 'win> Renderer<'sdl, 'win> {
   pub fn present(&self) {
     unsafe { SDL_RenderPresent(self.ptr) };
   }
+
+  /// Presents the backbuffer, and gives how long the present took.
+  ///
+  /// With vsync on, this is mostly time spent waiting for the display, so
+  /// subtracting it from your frame time tells you how much of the frame was
+  /// actually spent on the CPU. Otherwise this is the same as
+  /// [present](Renderer::present).
+  pub fn present_timed(&self) -> Duration {
+    let start = unsafe { SDL_GetPerformanceCounter() };
+    unsafe { SDL_RenderPresent(self.ptr) };
+    let end = unsafe { SDL_GetPerformanceCounter() };
+    let frequency = unsafe { SDL_GetPerformanceFrequency() };
+    let ticks = end.wrapping_sub(start);
+    // Note: this splits the division so that large tick counts can't overflow.
+    let secs = ticks / frequency;
+    let nanos = (ticks % frequency) * 1_000_000_000 / frequency;
+    Duration::new(secs, nanos as u32)
+  }
+}
+
+/// The `(x, y)` offsets of one octant (where `x >= y`) of a midpoint circle.
+fn circle_octant(radius: i32) -> Vec<(i32, i32)> {
+  let mut out = Vec::new();
+  let mut x = radius;
+  let mut y = 0;
+  let mut err = 1 - radius;
+  while x >= y {
+    out.push((x, y));
+    y += 1;
+    if err < 0 {
+      err += 2 * y + 1;
+    } else {
+      x -= 1;
+      err += 2 * (y - x) + 1;
+    }
+  }
+  out
+}
+
+/// For each row offset `0..=radius` from the center, the horizontal distance
+/// to the edge of a midpoint circle.
+fn circle_half_widths(radius: i32) -> Vec<i32> {
+  let mut out = vec![0; radius as usize + 1];
+  for (x, y) in circle_octant(radius) {
+    out[y as usize] = out[y as usize].max(x);
+    out[x as usize] = out[x as usize].max(y);
+  }
+  out
+}
+#[test]
+fn test_circle_half_widths() {
+  assert_eq!(circle_half_widths(0), vec![0]);
+  assert_eq!(circle_half_widths(1), vec![1, 0]);
+  assert_eq!(circle_half_widths(3), vec![3, 3, 2, 1]);
+  assert_eq!(circle_half_widths(5), vec![5, 5, 5, 4, 3, 2]);
+}
+
+/// The color for step `i` of a gradient with `steps` total steps.
+fn gradient_color(start: Color, end: Color, i: i32, steps: i32) -> Color {
+  let t = if steps > 1 {
+    i as f32 / (steps - 1) as f32
+  } else {
+    0.0
+  };
+  let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+  Color {
+    r: lerp(start.r, end.r),
+    g: lerp(start.g, end.g),
+    b: lerp(start.b, end.b),
+    a: lerp(start.a, end.a),
+  }
+}
+#[rustfmt::skip]
+#[test]
+fn test_gradient_color() {
+  let black = Color { r: 0, g: 0, b: 0, a: 255 };
+  let white = Color { r: 255, g: 255, b: 255, a: 255 };
+  assert_eq!(gradient_color(black, white, 0, 3), black);
+  assert_eq!(gradient_color(black, white, 1, 3), Color { r: 128, g: 128, b: 128, a: 255 });
+  assert_eq!(gradient_color(black, white, 2, 3), white);
+  assert_eq!(gradient_color(white, black, 0, 1), white);
+}
+
+/// The rect of a glyph within a glyph atlas.
+fn glyph_rect(glyph: u8, glyph_size: (i32, i32), atlas_cols: i32) -> Rect {
+  let index = i32::from(glyph);
+  Rect {
+    x: (index % atlas_cols) * glyph_size.0,
+    y: (index / atlas_cols) * glyph_size.1,
+    w: glyph_size.0,
+    h: glyph_size.1,
+  }
+}
+#[rustfmt::skip]
+#[test]
+fn test_glyph_rect() {
+  assert_eq!(glyph_rect(0, (8, 12), 16), Rect { x: 0, y: 0, w: 8, h: 12 });
+  assert_eq!(glyph_rect(b'A', (8, 12), 16), Rect { x: 8, y: 48, w: 8, h: 12 });
+  assert_eq!(glyph_rect(b'~', (6, 6), 32), Rect { x: 180, y: 18, w: 6, h: 6 });
+}
+
+/// Scan converts a polygon into one rect for each span of each row.
+///
+/// A pixel is inside the polygon if its center is inside (even-odd rule).
+fn polygon_spans(points: &[Point]) -> Vec<Rect> {
+  let mut out = Vec::new();
+  if points.len() < 3 {
+    return out;
+  }
+  let min_y = points.iter().map(|p| p.y).min().unwrap();
+  let max_y = points.iter().map(|p| p.y).max().unwrap();
+  let mut crossings: Vec<f32> = Vec::new();
+  for y in min_y..max_y {
+    let scan_y = y as f32 + 0.5;
+    crossings.clear();
+    for (i, a) in points.iter().enumerate() {
+      let b = points[(i + 1) % points.len()];
+      let (ay, by) = (a.y as f32, b.y as f32);
+      if (ay <= scan_y && scan_y < by) || (by <= scan_y && scan_y < ay) {
+        let t = (scan_y - ay) / (by - ay);
+        crossings.push(a.x as f32 + t * (b.x - a.x) as f32);
+      }
+    }
+    crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    for pair in crossings.chunks_exact(2) {
+      let start = (pair[0] - 0.5).ceil() as i32;
+      let end = (pair[1] - 0.5).ceil() as i32;
+      if end > start {
+        out.push(Rect {
+          x: start,
+          y,
+          w: end - start,
+          h: 1,
+        });
+      }
+    }
+  }
+  out
+}
+#[rustfmt::skip]
+#[test]
+fn test_polygon_spans() {
+  let square = [
+    Point { x: 0, y: 0 },
+    Point { x: 4, y: 0 },
+    Point { x: 4, y: 2 },
+    Point { x: 0, y: 2 },
+  ];
+  assert_eq!(
+    polygon_spans(&square),
+    vec![Rect { x: 0, y: 0, w: 4, h: 1 }, Rect { x: 0, y: 1, w: 4, h: 1 }]
+  );
+  let triangle = [Point { x: 0, y: 0 }, Point { x: 4, y: 4 }, Point { x: 0, y: 4 }];
+  assert_eq!(
+    polygon_spans(&triangle),
+    vec![
+      Rect { x: 0, y: 1, w: 1, h: 1 },
+      Rect { x: 0, y: 2, w: 2, h: 1 },
+      Rect { x: 0, y: 3, w: 3, h: 1 },
+    ]
+  );
+  assert!(polygon_spans(&square[..2]).is_empty());
 }
@@ -0,0 +1,82 @@
+use super::*;
+
+/// A list of sprites that all get drawn from the same texture.
+///
+/// Push each sprite with [push](SpriteList::push), then draw them all with
+/// [flush](SpriteList::flush). The list isn't cleared by a flush, so
+/// something like a tile map can be built once and drawn every frame.
+///
+/// This is only a convenience: the flush is one `copy` per sprite, so it's no
+/// faster than doing those copies yourself. The texture's color mod is only
+/// changed when the color actually changes from one sprite to the next.
+#[derive(Debug, Clone)]
+pub struct SpriteList<'b, 'sdl, 'win, 'ren> {
+  texture: &'b Texture<'sdl, 'win, 'ren>,
+  sprites: Vec<(Rect, FRect, Color)>,
+}
+impl<'b, 'sdl, 'win, 'ren> SpriteList<'b, 'sdl, 'win, 'ren> {
+  /// Makes a new, empty list for the texture given.
+  pub fn new(texture: &'b Texture<'sdl, 'win, 'ren>) -> Self {
+    Self {
+      texture,
+      sprites: Vec::new(),
+    }
+  }
+
+  /// Adds a sprite to the list.
+  ///
+  /// * `src` is the part of the texture to use.
+  /// * `dst` is where to draw it (rounded to whole pixels when drawn).
+  /// * `color` modulates the sprite, use white at full alpha for no change.
+  pub fn push(&mut self, src: Rect, dst: FRect, color: Color) {
+    self.sprites.push((src, dst, color));
+  }
+
+  /// The number of sprites in the list.
+  pub fn len(&self) -> usize {
+    self.sprites.len()
+  }
+
+  /// If the list has no sprites.
+  pub fn is_empty(&self) -> bool {
+    self.sprites.is_empty()
+  }
+
+  /// Removes all sprites from the list.
+  pub fn clear(&mut self) {
+    self.sprites.clear();
+  }
+
+  /// Draws every sprite in the list, in the order they were pushed.
+  ///
+  /// The renderer must be the one that made the texture. The texture's color
+  /// and alpha mod are restored afterwards.
+  pub fn flush(&self, renderer: &Renderer) -> Result<(), String> {
+    let old_color = self.texture.color_mod()?;
+    let old_alpha = self.texture.alpha_mod()?;
+    let mut out = Ok(());
+    let mut current: Option<Color> = None;
+    for &(src, dst, color) in self.sprites.iter() {
+      if current != Some(color) {
+        out = self
+          .texture
+          .set_color_mod(color.r, color.g, color.b)
+          .and_then(|_| self.texture.set_alpha_mod(color.a));
+        if out.is_err() {
+          break;
+        }
+        current = Some(color);
+      }
+      out = renderer.copy(self.texture, Some(src), Some(dst.to_i_rounded()));
+      if out.is_err() {
+        break;
+      }
+    }
+    let (r, g, b) = old_color;
+    let restore = self
+      .texture
+      .set_color_mod(r, g, b)
+      .and_then(|_| self.texture.set_alpha_mod(old_alpha));
+    out.and(restore)
+  }
+}
@@ -0,0 +1,68 @@
+use super::*;
+
+/// Flags for which SDL2 subsystems to initialize.
+///
+/// See [init_subsystem](SDLToken::init_subsystem).
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(transparent)]
+pub struct InitFlags(pub(crate) u32);
+#[allow(missing_docs)]
+impl InitFlags {
+  phantom_fields! {
+    self.0: u32,
+    timer: SDL_INIT_TIMER,
+    audio: SDL_INIT_AUDIO,
+    /// Video implies `events`.
+    video: SDL_INIT_VIDEO,
+    /// Joystick implies `events`.
+    joystick: SDL_INIT_JOYSTICK,
+    haptic: SDL_INIT_HAPTIC,
+    /// Game controller implies `joystick`.
+    game_controller: SDL_INIT_GAMECONTROLLER,
+    events: SDL_INIT_EVENTS,
+  }
+}
+
+impl SDLToken {
+  /// Initializes the subsystems given, and gives a guard that shuts them down
+  /// again when dropped.
+  ///
+  /// SDL2 keeps a count of how many times each subsystem has been initialized,
+  /// and a subsystem only actually shuts down once every init has had a
+  /// matching quit, so it's fine to have more than one guard active for the
+  /// same subsystem at once.
+  ///
+  /// Note that [init](init) currently starts up every subsystem for you, so
+  /// these guards just add to that count until the `SDLToken` is dropped.
+  pub fn init_subsystem(&self, flags: InitFlags) -> Result<Subsystem<'_>, String> {
+    let out = unsafe { SDL_InitSubSystem(flags.0) };
+    if out == 0 {
+      Ok(Subsystem {
+        flags,
+        _marker: PhantomData,
+      })
+    } else {
+      Err(get_error())
+    }
+  }
+}
+
+/// Guard for subsystems started by [init_subsystem](SDLToken::init_subsystem).
+///
+/// Each subsystem's init count is decreased when this is dropped.
+#[derive(Debug)]
+pub struct Subsystem<'sdl> {
+  flags: InitFlags,
+  _marker: PhantomData<&'sdl SDLToken>,
+}
+impl<'sdl> Subsystem<'sdl> {
+  /// The subsystems that this guard holds.
+  pub fn flags(&self) -> InitFlags {
+    self.flags
+  }
+}
+impl<'sdl> Drop for Subsystem<'sdl> {
+  fn drop(&mut self) {
+    unsafe { SDL_QuitSubSystem(self.flags.0) }
+  }
+}
@@ -67,6 +67,36 @@ impl SurfaceFormat {
   };
 }
 
+/// Something that can decode image file data into raw pixels.
+///
+/// beryllium doesn't decode any image file formats itself, so this is how you
+/// plug in whatever image library you like. See
+/// [from_decoder](Surface::from_decoder).
+pub trait ImageDecoder {
+  /// Decodes the bytes of an image file.
+  ///
+  /// Gives back `(pixels, width, height, format)`. The pixels must be tightly
+  /// packed rows (no padding between rows) in the format given, which will
+  /// usually be `PixelFormatEnum::RGBA32`.
+  fn decode(bytes: &[u8]) -> Result<(Vec<u8>, i32, i32, PixelFormatEnum), String>;
+}
+
+/// Something that can encode raw pixels into image file data.
+///
+/// This is the other half of [ImageDecoder], so that you can save images with
+/// whatever image library you like. See
+/// [save_with_encoder](Surface::save_with_encoder).
+pub trait ImageEncoder {
+  /// Encodes pixels into the bytes of an image file.
+  ///
+  /// The pixels are tightly packed rows (no padding between rows) in the
+  /// format given. If your encoder only handles some formats, you can use
+  /// [convert_format](Surface::convert_format) before saving.
+  fn encode(
+    pixels: &[u8], width: i32, height: i32, format: PixelFormatEnum,
+  ) -> Result<Vec<u8>, String>;
+}
+
 /// Handle to a "surface", a CPU-side image.
 ///
 /// This is fairly easy to edit, but you have to upload it to the GPU before you
@@ -83,6 +113,14 @@ impl<'sdl> Drop for Surface<'sdl> {
   }
 }
 impl<'sdl> Surface<'sdl> {
+  /// Gets the raw `*mut SDL_Surface` pointer, for use with other C libraries.
+  ///
+  /// The surface is still owned by this value: don't free the pointer, and don't
+  /// use it after this value has been dropped.
+  pub fn as_raw(&self) -> *mut SDL_Surface {
+    self.ptr
+  }
+
   /// Lock, edit, unlock, as one easy cycle.
   ///
   /// If the Surface cannot be locked you'll get an error, otherwise your
@@ -105,6 +143,300 @@ impl<'sdl> Surface<'sdl> {
     }
   }
 
+  /// Makes a new surface from image file data, using the decoder given.
+  ///
+  /// The surface has the pixel format that the decoder picks. Formats with
+  /// less than 8 bits per pixel, and FourCC formats, aren't supported.
+  pub fn from_decoder<D: ImageDecoder>(
+    _sdl: &'sdl SDLToken, bytes: &[u8],
+  ) -> Result<Surface<'sdl>, String> {
+    let (pixels, w, h, format) = D::decode(bytes)?;
+    if w <= 0 || h <= 0 {
+      return Err("beryllium error: the decoded image is empty.".to_string());
+    }
+    let bytes_per_pixel = format.bytes_per_pixel() as usize;
+    if bytes_per_pixel == 0 || format.is_fourcc() {
+      return Err("beryllium error: unsupported decoded pixel format.".to_string());
+    }
+    let row_len = w as usize * bytes_per_pixel;
+    if pixels.len() < row_len * h as usize {
+      return Err("beryllium error: the decoded pixel data is too short.".to_string());
+    }
+    let depth = format.bits_per_pixel() as i32;
+    let ptr = unsafe { SDL_CreateRGBSurfaceWithFormat(0, w, h, depth, format as u32) };
+    if ptr.is_null() {
+      return Err(get_error());
+    }
+    let surface = Surface {
+      ptr,
+      _marker: PhantomData,
+    };
+    unsafe {
+      // Note: the new surface isn't RLE encoded, so we don't need to lock it.
+      let base = (*ptr).pixels as *mut u8;
+      let pitch = (*ptr).pitch as usize;
+      for (y, row) in pixels.chunks_exact(row_len).take(h as usize).enumerate() {
+        core::ptr::copy_nonoverlapping(row.as_ptr(), base.add(y * pitch), row_len);
+      }
+    }
+    Ok(surface)
+  }
+
+  /// Encodes the surface with the encoder given, and writes it to a file.
+  ///
+  /// You get an error if the surface format is less than 8 bits per pixel,
+  /// if the encoder fails, or if the file can't be written. The encoder isn't
+  /// given the palette, so convert an indexed surface to a direct format
+  /// first.
+  pub fn save_with_encoder<E: ImageEncoder, P: AsRef<std::path::Path>>(
+    &self, path: P,
+  ) -> Result<(), String> {
+    let (format, bits, bytes_per_pixel) = unsafe {
+      let f = &*(*self.ptr).format;
+      (
+        PixelFormatEnum::from(f.format),
+        f.BitsPerPixel,
+        usize::from(f.BytesPerPixel),
+      )
+    };
+    if bits < 8 || format.is_fourcc() {
+      return Err("beryllium error: unsupported surface pixel format.".to_string());
+    }
+    let (w, h) = (self.width(), self.height());
+    let row_len = w as usize * bytes_per_pixel;
+    let mut pixels = Vec::with_capacity(row_len * h as usize);
+    unsafe {
+      if SDL_LockSurface(self.ptr) != 0 {
+        return Err(get_error());
+      }
+      let base = (*self.ptr).pixels as *const u8;
+      let pitch = (*self.ptr).pitch as usize;
+      for y in 0..(h as usize) {
+        pixels.extend_from_slice(from_raw_parts(base.add(y * pitch), row_len));
+      }
+      SDL_UnlockSurface(self.ptr);
+    }
+    let file_bytes = E::encode(&pixels, w, h, format)?;
+    std::fs::write(path, file_bytes).map_err(|e| e.to_string())
+  }
+
+  /// Makes a new surface that's a copy of this one.
+  ///
+  /// The copy has its own pixel buffer, and keeps the same format, palette,
+  /// color key, and other blit settings as the original.
+  pub fn duplicate(&self) -> Result<Surface<'sdl>, String> {
+    let ptr: *mut SDL_Surface = unsafe { SDL_DuplicateSurface(self.ptr) };
+    if ptr.is_null() {
+      Err(get_error())
+    } else {
+      Ok(Surface {
+        ptr,
+        _marker: PhantomData,
+      })
+    }
+  }
+
+  /// Makes a copy of this surface converted to the pixel format given.
+  pub fn convert_format(&self, format: PixelFormatEnum) -> Result<Surface<'sdl>, String> {
+    let ptr: *mut SDL_Surface = unsafe { SDL_ConvertSurfaceFormat(self.ptr, format as u32, 0) };
+    if ptr.is_null() {
+      Err(get_error())
+    } else {
+      Ok(Surface {
+        ptr,
+        _marker: PhantomData,
+      })
+    }
+  }
+
+  /// Makes a new surface that's this surface mirrored left to right.
+  ///
+  /// The new surface has the same format and palette, but unlike with
+  /// [duplicate](Surface::duplicate) the other blit settings (color key, blend
+  /// mode, etc) are not copied over. Formats with less than 8 bits per pixel
+  /// aren't supported.
+  pub fn flip_horizontal(&self) -> Result<Surface<'sdl>, String> {
+    let (w, h) = (self.width(), self.height());
+    self.remapped(w, h, |x, y| (w - 1 - x, y))
+  }
+
+  /// Makes a new surface that's this surface mirrored top to bottom.
+  ///
+  /// Formats with less than 8 bits per pixel aren't supported.
+  pub fn flip_vertical(&self) -> Result<Surface<'sdl>, String> {
+    let (w, h) = (self.width(), self.height());
+    self.remapped(w, h, |x, y| (x, h - 1 - y))
+  }
+
+  /// Makes a new surface that's this surface rotated clockwise by `turns`
+  /// quarter turns.
+  ///
+  /// An odd number of turns swaps the width and height. Formats with less
+  /// than 8 bits per pixel aren't supported.
+  pub fn rotate_90(&self, turns: u32) -> Result<Surface<'sdl>, String> {
+    let (w, h) = (self.width(), self.height());
+    match turns % 4 {
+      0 => self.remapped(w, h, |x, y| (x, y)),
+      1 => self.remapped(h, w, |x, y| (y, h - 1 - x)),
+      2 => self.remapped(w, h, |x, y| (w - 1 - x, h - 1 - y)),
+      _ => self.remapped(h, w, |x, y| (w - 1 - y, x)),
+    }
+  }
+
+  /// Reads the color of a single pixel.
+  ///
+  /// You get an error if the position is out of bounds, or if the surface
+  /// format is less than 8 bits per pixel.
+  pub fn get_pixel(&self, x: i32, y: i32) -> Result<Color, String> {
+    let offset = self.pixel_offset(x, y)?;
+    let mut color = Color::default();
+    unsafe {
+      if SDL_LockSurface(self.ptr) != 0 {
+        return Err(get_error());
+      }
+      let format = (*self.ptr).format;
+      let p = ((*self.ptr).pixels as *const u8).add(offset);
+      let pixel = read_pixel(p, usize::from((*format).BytesPerPixel));
+      SDL_UnlockSurface(self.ptr);
+      SDL_GetRGBA(
+        pixel,
+        format,
+        &mut color.r,
+        &mut color.g,
+        &mut color.b,
+        &mut color.a,
+      );
+    }
+    Ok(color)
+  }
+
+  /// Writes the color of a single pixel.
+  ///
+  /// The color is mapped to the closest value that the surface format can
+  /// store. You get an error if the position is out of bounds, or if the
+  /// surface format is less than 8 bits per pixel.
+  pub fn set_pixel(&mut self, x: i32, y: i32, color: Color) -> Result<(), String> {
+    let offset = self.pixel_offset(x, y)?;
+    unsafe {
+      let format = (*self.ptr).format;
+      let pixel = SDL_MapRGBA(format, color.r, color.g, color.b, color.a);
+      if SDL_LockSurface(self.ptr) != 0 {
+        return Err(get_error());
+      }
+      let p = ((*self.ptr).pixels as *mut u8).add(offset);
+      write_pixel(p, usize::from((*format).BytesPerPixel), pixel);
+      SDL_UnlockSurface(self.ptr);
+    }
+    Ok(())
+  }
+
+  /// Checks if this surface and another surface have any solid pixels in the
+  /// same place, with each surface at the position given.
+  ///
+  /// This is "pixel perfect" collision: only the area where the two surfaces
+  /// overlap is checked, and a pixel counts as solid unless it's the
+  /// surface's color key or it has an alpha of 0. Surfaces without an alpha
+  /// channel or a color key are solid everywhere, which makes this the same
+  /// as a plain rectangle check.
+  ///
+  /// You get an error if either surface format is less than 8 bits per pixel.
+  pub fn pixels_overlap(
+    &self, self_pos: Point, other: &Surface, other_pos: Point,
+  ) -> Result<bool, String> {
+    let area = match overlap_area(
+      (self_pos.x, self_pos.y, self.width(), self.height()),
+      (other_pos.x, other_pos.y, other.width(), other.height()),
+    ) {
+      Some(area) => area,
+      None => return Ok(false),
+    };
+    let (x, y, w, h) = area;
+    let a = SolidPixels::lock(self)?;
+    let b = SolidPixels::lock(other)?;
+    for row in y..(y + h) {
+      for col in x..(x + w) {
+        let a_solid = unsafe { a.is_solid(col - self_pos.x, row - self_pos.y) };
+        if a_solid && unsafe { b.is_solid(col - other_pos.x, row - other_pos.y) } {
+          return Ok(true);
+        }
+      }
+    }
+    Ok(false)
+  }
+
+  /// The byte offset of a pixel within the pixel data, after checking the
+  /// position and the format.
+  fn pixel_offset(&self, x: i32, y: i32) -> Result<usize, String> {
+    if x < 0 || y < 0 || x >= self.width() || y >= self.height() {
+      return Err("beryllium error: pixel position out of bounds.".to_string());
+    }
+    let (bits, bytes) = unsafe {
+      let f = &*(*self.ptr).format;
+      (f.BitsPerPixel, usize::from(f.BytesPerPixel))
+    };
+    if bits < 8 {
+      return Err("beryllium error: surface format must be at least 8 bits per pixel.".to_string());
+    }
+    Ok(y as usize * self.pitch() as usize + x as usize * bytes)
+  }
+
+  /// Makes a new `new_w` by `new_h` surface of the same format, where each
+  /// output pixel `(x, y)` is copied from the pixel `source_of(x, y)` of this
+  /// surface.
+  fn remapped<F: Fn(i32, i32) -> (i32, i32)>(
+    &self, new_w: i32, new_h: i32, source_of: F,
+  ) -> Result<Surface<'sdl>, String> {
+    let (format, bits, bytes, palette) = unsafe {
+      let f = &*(*self.ptr).format;
+      (
+        f.format,
+        f.BitsPerPixel,
+        usize::from(f.BytesPerPixel),
+        f.palette,
+      )
+    };
+    if bits < 8 {
+      return Err("beryllium error: surface format must be at least 8 bits per pixel.".to_string());
+    }
+    let out_ptr =
+      unsafe { SDL_CreateRGBSurfaceWithFormat(0, new_w, new_h, i32::from(bits), format) };
+    if out_ptr.is_null() {
+      return Err(get_error());
+    }
+    let out = Surface {
+      ptr: out_ptr,
+      _marker: PhantomData,
+    };
+    unsafe {
+      if !palette.is_null() && SDL_SetSurfacePalette(out.ptr, palette) != 0 {
+        return Err(get_error());
+      }
+      if SDL_LockSurface(self.ptr) != 0 {
+        return Err(get_error());
+      }
+      if SDL_LockSurface(out.ptr) != 0 {
+        let err = get_error();
+        SDL_UnlockSurface(self.ptr);
+        return Err(err);
+      }
+      let src_base = (*self.ptr).pixels as *const u8;
+      let src_pitch = (*self.ptr).pitch as isize;
+      let dst_base = (*out.ptr).pixels as *mut u8;
+      let dst_pitch = (*out.ptr).pitch as isize;
+      for y in 0..new_h {
+        for x in 0..new_w {
+          let (sx, sy) = source_of(x, y);
+          let src = src_base.offset(sy as isize * src_pitch + sx as isize * bytes as isize);
+          let dst = dst_base.offset(y as isize * dst_pitch + x as isize * bytes as isize);
+          core::ptr::copy_nonoverlapping(src, dst, bytes);
+        }
+      }
+      SDL_UnlockSurface(out.ptr);
+      SDL_UnlockSurface(self.ptr);
+    }
+    Ok(out)
+  }
+
   /// Width in pixels
   pub fn width(&self) -> i32 {
     unsafe { (*self.ptr).w }
@@ -168,4 +500,243 @@ impl<'sdl> Surface<'sdl> {
       Err(get_error())
     }
   }
+
+  /// Obtains the additional alpha value used in blit operations.
+  pub fn alpha_mod(&self) -> Result<u8, String> {
+    let mut alpha = 0;
+    let out = unsafe { SDL_GetSurfaceAlphaMod(self.ptr, &mut alpha) };
+    if out == 0 {
+      Ok(alpha)
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets an additional alpha value used in blit operations.
+  ///
+  /// When this surface is blitted, `srcA = srcA * (alpha / 255)`.
+  pub fn set_alpha_mod(&mut self, alpha: u8) -> Result<(), String> {
+    let out = unsafe { SDL_SetSurfaceAlphaMod(self.ptr, alpha) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Obtains the additional `(r, g, b)` color values used in blit operations.
+  pub fn color_mod(&self) -> Result<(u8, u8, u8), String> {
+    let mut r = 0;
+    let mut g = 0;
+    let mut b = 0;
+    let out = unsafe { SDL_GetSurfaceColorMod(self.ptr, &mut r, &mut g, &mut b) };
+    if out == 0 {
+      Ok((r, g, b))
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets additional color values used in blit operations.
+  ///
+  /// When this surface is blitted, each color channel is multiplied like
+  /// `srcC = srcC * (c / 255)`.
+  pub fn set_color_mod(&mut self, r: u8, g: u8, b: u8) -> Result<(), String> {
+    let out = unsafe { SDL_SetSurfaceColorMod(self.ptr, r, g, b) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the color key (the "transparent color") of the surface.
+  ///
+  /// The color is mapped into the surface's pixel format, and pixels of that
+  /// value are skipped when the surface is blitted or made into a texture. Use
+  /// `None` to turn the color key off.
+  pub fn set_color_key(&mut self, key: Option<Color>) -> Result<(), String> {
+    let out = unsafe {
+      match key {
+        Some(color) => {
+          let pixel = SDL_MapRGB((*self.ptr).format, color.r, color.g, color.b);
+          SDL_SetColorKey(self.ptr, SDL_TRUE as i32, pixel)
+        }
+        None => SDL_SetColorKey(self.ptr, SDL_FALSE as i32, 0),
+      }
+    };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Obtains the blend mode used for blit operations.
+  pub fn blend_mode(&self) -> Result<BlendMode, String> {
+    let mut mode = 0;
+    let out = unsafe { SDL_GetSurfaceBlendMode(self.ptr, &mut mode) };
+    if out == 0 {
+      Ok(BlendMode::from(mode))
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the blend mode used for blit operations.
+  ///
+  /// Note that `BlendMode::Invalid` isn't a mode you can actually set.
+  pub fn set_blend_mode(&mut self, mode: BlendMode) -> Result<(), String> {
+    let out = unsafe { SDL_SetSurfaceBlendMode(self.ptr, mode as SDL_BlendMode::Type) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+}
+
+/// A locked surface that can answer if a pixel is solid, for
+/// [pixels_overlap](Surface::pixels_overlap).
+///
+/// The surface is unlocked when this is dropped.
+struct SolidPixels<'a> {
+  ptr: *mut SDL_Surface,
+  _marker: PhantomData<&'a SDL_Surface>,
+  key: Option<u32>,
+  bytes: usize,
+  alpha_mask: u32,
+  has_palette: bool,
+}
+impl<'a> SolidPixels<'a> {
+  fn lock(surface: &'a Surface) -> Result<Self, String> {
+    let ptr = surface.ptr;
+    let (bits, bytes, alpha_mask, has_palette) = unsafe {
+      let f = &*(*ptr).format;
+      (
+        f.BitsPerPixel,
+        usize::from(f.BytesPerPixel),
+        f.Amask,
+        !f.palette.is_null(),
+      )
+    };
+    if bits < 8 {
+      return Err("beryllium error: surface format must be at least 8 bits per pixel.".to_string());
+    }
+    let mut key_value = 0;
+    let key = if unsafe { SDL_GetColorKey(ptr, &mut key_value) } == 0 {
+      Some(key_value)
+    } else {
+      None
+    };
+    if unsafe { SDL_LockSurface(ptr) } != 0 {
+      return Err(get_error());
+    }
+    Ok(Self {
+      ptr,
+      _marker: PhantomData,
+      key,
+      bytes,
+      alpha_mask,
+      has_palette,
+    })
+  }
+
+  /// Safety: the position must be within the surface.
+  unsafe fn is_solid(&self, x: i32, y: i32) -> bool {
+    let offset = y as usize * (*self.ptr).pitch as usize + x as usize * self.bytes;
+    let pixel = read_pixel(((*self.ptr).pixels as *const u8).add(offset), self.bytes);
+    if self.key == Some(pixel) {
+      false
+    } else if self.has_palette {
+      let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+      SDL_GetRGBA(pixel, (*self.ptr).format, &mut r, &mut g, &mut b, &mut a);
+      a != 0
+    } else if self.alpha_mask != 0 {
+      pixel & self.alpha_mask != 0
+    } else {
+      true
+    }
+  }
+}
+impl<'a> Drop for SolidPixels<'a> {
+  fn drop(&mut self) {
+    unsafe { SDL_UnlockSurface(self.ptr) }
+  }
+}
+
+/// The `(x, y, w, h)` area where two `(x, y, w, h)` rectangles overlap, or
+/// `None` if they don't.
+fn overlap_area(a: (i32, i32, i32, i32), b: (i32, i32, i32, i32)) -> Option<(i32, i32, i32, i32)> {
+  let left = a.0.max(b.0);
+  let top = a.1.max(b.1);
+  let right = a.0.saturating_add(a.2).min(b.0.saturating_add(b.2));
+  let bottom = a.1.saturating_add(a.3).min(b.1.saturating_add(b.3));
+  if left < right && top < bottom {
+    Some((left, top, right - left, bottom - top))
+  } else {
+    None
+  }
+}
+#[test]
+fn test_overlap_area() {
+  assert_eq!(
+    overlap_area((0, 0, 10, 10), (5, 5, 10, 10)),
+    Some((5, 5, 5, 5))
+  );
+  assert_eq!(
+    overlap_area((5, 5, 10, 10), (0, 0, 10, 10)),
+    Some((5, 5, 5, 5))
+  );
+  assert_eq!(
+    overlap_area((0, 0, 10, 10), (2, 3, 4, 4)),
+    Some((2, 3, 4, 4))
+  );
+  assert_eq!(overlap_area((0, 0, 10, 10), (10, 0, 10, 10)), None);
+  assert_eq!(overlap_area((0, 0, 10, 10), (-5, 20, 10, 10)), None);
+  assert_eq!(overlap_area((0, 0, 0, 10), (0, 0, 10, 10)), None);
+}
+
+/// Reads a `bytes` sized pixel value the same way that SDL2 does.
+unsafe fn read_pixel(p: *const u8, bytes: usize) -> u32 {
+  match bytes {
+    1 => u32::from(*p),
+    2 => u32::from((p as *const u16).read_unaligned()),
+    3 => {
+      let b = [*p, *p.add(1), *p.add(2)];
+      if cfg!(target_endian = "little") {
+        u32::from(b[0]) | u32::from(b[1]) << 8 | u32::from(b[2]) << 16
+      } else {
+        u32::from(b[0]) << 16 | u32::from(b[1]) << 8 | u32::from(b[2])
+      }
+    }
+    _ => (p as *const u32).read_unaligned(),
+  }
+}
+
+/// Writes a `bytes` sized pixel value the same way that SDL2 does.
+unsafe fn write_pixel(p: *mut u8, bytes: usize, pixel: u32) {
+  match bytes {
+    1 => *p = pixel as u8,
+    2 => (p as *mut u16).write_unaligned(pixel as u16),
+    3 => {
+      let b = if cfg!(target_endian = "little") {
+        [pixel as u8, (pixel >> 8) as u8, (pixel >> 16) as u8]
+      } else {
+        [(pixel >> 16) as u8, (pixel >> 8) as u8, pixel as u8]
+      };
+      core::ptr::copy_nonoverlapping(b.as_ptr(), p, 3);
+    }
+    _ => (p as *mut u32).write_unaligned(pixel),
+  }
+}
+#[test]
+fn test_read_write_pixel() {
+  let mut buf = [0_u8; 4];
+  for &(bytes, pixel) in &[(1, 0xAB), (2, 0xABCD), (3, 0xAB_CDEF), (4, 0x89AB_CDEF)] {
+    unsafe {
+      write_pixel(buf.as_mut_ptr(), bytes, pixel);
+      assert_eq!(read_pixel(buf.as_ptr(), bytes), pixel);
+    }
+  }
 }
@@ -4,10 +4,17 @@ use super::*;
 ///
 /// This is harder to directly edit, but operations are faster, and you can
 /// display it in the Window.
+///
+/// The size and format of a texture can't change once it's made, so they're
+/// kept with the handle and [width](Texture::width),
+/// [height](Texture::height), [size](Texture::size), and
+/// [format](Texture::format) don't need to ask SDL2 each time.
 #[derive(Debug)]
-#[repr(transparent)]
 pub struct Texture<'sdl, 'win, 'ren> {
   pub(crate) ptr: *mut SDL_Texture,
+  pub(crate) width: i32,
+  pub(crate) height: i32,
+  pub(crate) format: PixelFormatEnum,
   pub(crate) _marker: PhantomData<&'ren Renderer<'sdl, 'win>>,
 }
 impl<'sdl, 'win, 'ren> Drop for Texture<'sdl, 'win, 'ren> {
@@ -15,3 +22,466 @@ impl<'sdl, 'win, 'ren> Drop for Texture<'sdl, 'win, 'ren> {
     unsafe { SDL_DestroyTexture(self.ptr) }
   }
 }
+impl<'sdl, 'win, 'ren> Texture<'sdl, 'win, 'ren> {
+  /// Wraps a freshly made texture pointer, reading its size and format.
+  ///
+  /// The pointer is destroyed if this fails.
+  pub(crate) fn from_ptr(ptr: *mut SDL_Texture) -> Result<Self, String> {
+    if ptr.is_null() {
+      return Err(get_error());
+    }
+    let mut format = 0;
+    let mut width = 0;
+    let mut height = 0;
+    let out = unsafe { SDL_QueryTexture(ptr, &mut format, null_mut(), &mut width, &mut height) };
+    if out == 0 {
+      Ok(Texture {
+        ptr,
+        width,
+        height,
+        format: PixelFormatEnum::from(format),
+        _marker: PhantomData,
+      })
+    } else {
+      let err = get_error();
+      unsafe { SDL_DestroyTexture(ptr) };
+      Err(err)
+    }
+  }
+
+  /// The width of the texture, in pixels.
+  pub fn width(&self) -> i32 {
+    self.width
+  }
+
+  /// The height of the texture, in pixels.
+  pub fn height(&self) -> i32 {
+    self.height
+  }
+
+  /// The `(width, height)` of the texture, in pixels.
+  pub fn size(&self) -> (i32, i32) {
+    (self.width, self.height)
+  }
+
+  /// The `(w, h)` of `rect`, or of the whole texture if it's None.
+  ///
+  /// SDL2 doesn't clip these areas itself, so anything that isn't entirely
+  /// inside the texture is an error.
+  fn area_size(&self, rect: Option<Rect>) -> Result<(i32, i32), String> {
+    match rect {
+      None => Ok(self.size()),
+      Some(r) if rect_is_inside(r, self.width, self.height) => Ok((r.w, r.h)),
+      Some(_) => Err("beryllium error: the area isn't inside the texture.".to_string()),
+    }
+  }
+
+  /// The pixel format of the texture.
+  pub fn format(&self) -> PixelFormatEnum {
+    self.format
+  }
+
+  /// Asks SDL2 for all of the texture's info.
+  ///
+  /// Unlike the size and format this includes the texture's access, which
+  /// you'd otherwise have to remember yourself.
+  pub fn query(&self) -> Result<TextureInfo, String> {
+    let mut format = 0;
+    let mut access = 0;
+    let mut width = 0;
+    let mut height = 0;
+    let out =
+      unsafe { SDL_QueryTexture(self.ptr, &mut format, &mut access, &mut width, &mut height) };
+    if out == 0 {
+      let access = if access == TextureAccess::Streaming as i32 {
+        TextureAccess::Streaming
+      } else if access == TextureAccess::Target as i32 {
+        TextureAccess::Target
+      } else {
+        TextureAccess::Static
+      };
+      Ok(TextureInfo {
+        format: PixelFormatEnum::from(format),
+        access,
+        width,
+        height,
+      })
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets the raw `*mut SDL_Texture` pointer, for use with other C libraries.
+  ///
+  /// The texture is still owned by this value: don't free the pointer, and don't
+  /// use it after this value has been dropped.
+  pub fn as_raw(&self) -> *mut SDL_Texture {
+    self.ptr
+  }
+
+  /// Gets the color modulation used for copy operations, as (r, g, b).
+  pub fn color_mod(&self) -> Result<(u8, u8, u8), String> {
+    let mut r = 0;
+    let mut g = 0;
+    let mut b = 0;
+    let out = unsafe { SDL_GetTextureColorMod(self.ptr, &mut r, &mut g, &mut b) };
+    if out == 0 {
+      Ok((r, g, b))
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the color modulation used for copy operations.
+  ///
+  /// Each color channel of the texture is multiplied by `c / 255` as it's
+  /// drawn.
+  pub fn set_color_mod(&self, r: u8, g: u8, b: u8) -> Result<(), String> {
+    let out = unsafe { SDL_SetTextureColorMod(self.ptr, r, g, b) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets the alpha modulation used for copy operations.
+  pub fn alpha_mod(&self) -> Result<u8, String> {
+    let mut alpha = 0;
+    let out = unsafe { SDL_GetTextureAlphaMod(self.ptr, &mut alpha) };
+    if out == 0 {
+      Ok(alpha)
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the alpha modulation used for copy operations.
+  ///
+  /// The alpha of the texture is multiplied by `alpha / 255` as it's drawn.
+  pub fn set_alpha_mod(&self, alpha: u8) -> Result<(), String> {
+    let out = unsafe { SDL_SetTextureAlphaMod(self.ptr, alpha) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Destroys the texture right now, consuming it.
+  ///
+  /// This is what dropping the texture does too, but since `Drop` can't
+  /// report problems this gives an error if SDL2 sets one during the
+  /// cleanup.
+  pub fn destroy(self) -> Result<(), String> {
+    let ptr = self.ptr;
+    core::mem::forget(self);
+    clear_error();
+    unsafe { SDL_DestroyTexture(ptr) };
+    let err = get_error();
+    if err.is_empty() {
+      Ok(())
+    } else {
+      Err(err)
+    }
+  }
+
+  /// Obtains the blend mode used for copy operations.
+  pub fn blend_mode(&self) -> Result<BlendMode, String> {
+    let mut mode = 0;
+    let out = unsafe { SDL_GetTextureBlendMode(self.ptr, &mut mode) };
+    if out == 0 {
+      Ok(BlendMode::from(mode))
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the blend mode used for copy operations.
+  ///
+  /// Note that `BlendMode::Invalid` isn't a mode you can actually set.
+  pub fn set_blend_mode(&self, mode: BlendMode) -> Result<(), String> {
+    let out = unsafe { SDL_SetTextureBlendMode(self.ptr, mode as SDL_BlendMode::Type) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Updates an area of the texture with new pixel data.
+  ///
+  /// * `rect`: The area to update. If None, the whole texture is updated.
+  /// * `pitch`: The number of bytes from the start of one row to the next.
+  ///
+  /// The pixels must be in the texture's [format](Texture::format), and
+  /// there must be room for all of the area's rows at the pitch given. The
+  /// area must be entirely inside the texture. For planar YUV textures use
+  /// [update_yuv](Texture::update_yuv).
+  pub fn update(&self, rect: Option<Rect>, pixels: &[u8], pitch: i32) -> Result<(), String> {
+    if self.format.is_fourcc() {
+      return Err("beryllium error: use update_yuv for YUV textures.".to_string());
+    }
+    let (w, h) = self.area_size(rect)?;
+    let row_len = w.saturating_mul(self.format.bytes_per_pixel() as i32);
+    if !yuv_plane_fits(pixels, pitch, row_len, h) {
+      return Err("beryllium error: the pixel data is too small for the area.".to_string());
+    }
+    let out = unsafe {
+      let rect_ptr = core::mem::transmute::<Option<&Rect>, *const SDL_Rect>(rect.as_ref());
+      SDL_UpdateTexture(self.ptr, rect_ptr, pixels.as_ptr() as *const c_void, pitch)
+    };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Updates an area of a planar YUV texture with new pixel data.
+  ///
+  /// * `rect`: The area to update. If None, the whole texture is updated. It
+  ///   must be entirely inside the texture.
+  ///
+  /// The texture must use the `YV12` or `IYUV` format. The Y plane is full
+  /// size, and the U and V planes are half size in each direction (rounded
+  /// up). Each plane must have room for all of its rows at the pitch given.
+  #[allow(clippy::too_many_arguments)]
+  pub fn update_yuv(
+    &self, rect: Option<Rect>, y_plane: &[u8], y_pitch: i32, u_plane: &[u8], u_pitch: i32,
+    v_plane: &[u8], v_pitch: i32,
+  ) -> Result<(), String> {
+    match self.format {
+      PixelFormatEnum::YV12 | PixelFormatEnum::IYUV => (),
+      _ => return Err("beryllium error: the texture isn't a planar YUV texture.".to_string()),
+    }
+    let (w, h) = self.area_size(rect)?;
+    let chroma_w = (w + 1) / 2;
+    let chroma_h = (h + 1) / 2;
+    if !yuv_plane_fits(y_plane, y_pitch, w, h)
+      || !yuv_plane_fits(u_plane, u_pitch, chroma_w, chroma_h)
+      || !yuv_plane_fits(v_plane, v_pitch, chroma_w, chroma_h)
+    {
+      return Err("beryllium error: a plane is too small for the area.".to_string());
+    }
+    let out = unsafe {
+      let rect_ptr = core::mem::transmute::<Option<&Rect>, *const SDL_Rect>(rect.as_ref());
+      SDL_UpdateYUVTexture(
+        self.ptr,
+        rect_ptr,
+        y_plane.as_ptr(),
+        y_pitch,
+        u_plane.as_ptr(),
+        u_pitch,
+        v_plane.as_ptr(),
+        v_pitch,
+      )
+    };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Locks an area of a streaming texture so that you can edit it as a
+  /// `Surface`.
+  ///
+  /// * `rect`: The area to lock. If None, the whole texture is locked. It
+  ///   must be entirely inside the texture.
+  ///
+  /// The texture must have been made with `TextureAccess::Streaming`. The
+  /// lock is write-only: the surface does _not_ start with the texture's old
+  /// pixel data, so you should write every pixel of the area. The texture is
+  /// updated and unlocked when the lock is dropped.
+  ///
+  /// This doesn't use `SDL_LockTextureToSurface`. It's a normal texture lock,
+  /// and then a surface (that doesn't own its pixels) sized to the area and
+  /// pointing at the locked pixels.
+  pub fn lock_to_surface<'tex>(
+    &'tex mut self, rect: Option<Rect>,
+  ) -> Result<TextureSurfaceLock<'tex>, String> {
+    let (w, h) = self.area_size(rect)?;
+    let depth = self.format.bits_per_pixel() as i32;
+    let mut pixels: *mut c_void = null_mut();
+    let mut pitch = 0;
+    let out = unsafe {
+      let rect_ptr = core::mem::transmute::<Option<&Rect>, *const SDL_Rect>(rect.as_ref());
+      SDL_LockTexture(self.ptr, rect_ptr, &mut pixels, &mut pitch)
+    };
+    if out != 0 {
+      return Err(get_error());
+    }
+    let surf_ptr =
+      unsafe { SDL_CreateRGBSurfaceWithFormatFrom(pixels, w, h, depth, pitch, self.format as u32) };
+    if surf_ptr.is_null() {
+      let out = get_error();
+      unsafe { SDL_UnlockTexture(self.ptr) };
+      Err(out)
+    } else {
+      Ok(TextureSurfaceLock {
+        texture: self.ptr,
+        surface: Surface {
+          ptr: surf_ptr,
+          _marker: PhantomData,
+        },
+        _marker: PhantomData,
+      })
+    }
+  }
+}
+
+/// Checks that `r` is entirely inside of a `width` by `height` area.
+fn rect_is_inside(r: Rect, width: i32, height: i32) -> bool {
+  r.x >= 0 && r.y >= 0 && r.w >= 0 && r.h >= 0 && r.x <= width - r.w && r.y <= height - r.h
+}
+#[test]
+fn test_rect_is_inside() {
+  let r = |x, y, w, h| Rect { x, y, w, h };
+  assert!(rect_is_inside(r(0, 0, 4, 3), 4, 3));
+  assert!(rect_is_inside(r(1, 2, 3, 1), 4, 3));
+  assert!(rect_is_inside(r(4, 3, 0, 0), 4, 3));
+  assert!(!rect_is_inside(r(1, 0, 4, 3), 4, 3));
+  assert!(!rect_is_inside(r(0, 1, 4, 3), 4, 3));
+  assert!(!rect_is_inside(r(-1, 0, 2, 2), 4, 3));
+  assert!(!rect_is_inside(r(0, -1, 2, 2), 4, 3));
+  assert!(!rect_is_inside(r(2, 2, -1, 1), 4, 3));
+  assert!(!rect_is_inside(r(2, 2, 1, -1), 4, 3));
+  assert!(!rect_is_inside(r(i32::MAX, 0, 1, 1), 4, 3));
+}
+
+/// Checks that a plane of `w` by `h` one byte samples fits in the slice.
+///
+/// For packed formats `w` is the length of a row in bytes. An empty plane
+/// always fits, but a negative size never does.
+fn yuv_plane_fits(plane: &[u8], pitch: i32, w: i32, h: i32) -> bool {
+  if w < 0 || h < 0 {
+    return false;
+  }
+  if w == 0 || h == 0 {
+    return true;
+  }
+  if pitch < w {
+    return false;
+  }
+  let needed = pitch as usize * (h as usize - 1) + w as usize;
+  plane.len() >= needed
+}
+#[test]
+fn test_yuv_plane_fits() {
+  assert!(yuv_plane_fits(&[0; 12], 4, 4, 3));
+  assert!(yuv_plane_fits(&[0; 10], 4, 2, 3));
+  assert!(!yuv_plane_fits(&[0; 11], 4, 4, 3));
+  assert!(!yuv_plane_fits(&[0; 100], 3, 4, 3));
+  assert!(yuv_plane_fits(&[], 0, 0, 0));
+  assert!(!yuv_plane_fits(&[0; 100], 4, -1, 3));
+  assert!(!yuv_plane_fits(&[0; 100], 4, 4, -1));
+}
+
+/// A streaming texture that's locked and being edited as a `Surface`.
+///
+/// See [lock_to_surface](Texture::lock_to_surface). The texture is unlocked
+/// when this is dropped.
+#[derive(Debug)]
+pub struct TextureSurfaceLock<'tex> {
+  texture: *mut SDL_Texture,
+  surface: Surface<'tex>,
+  _marker: PhantomData<&'tex mut SDL_Texture>,
+}
+impl<'tex> TextureSurfaceLock<'tex> {
+  /// The surface view of the locked area.
+  pub fn surface(&self) -> &Surface<'tex> {
+    &self.surface
+  }
+
+  /// The surface view of the locked area, for editing.
+  pub fn surface_mut(&mut self) -> &mut Surface<'tex> {
+    &mut self.surface
+  }
+}
+impl<'tex> Drop for TextureSurfaceLock<'tex> {
+  fn drop(&mut self) {
+    // Note: The surface doesn't own the pixels, so it's fine for it to be
+    // freed after the unlock (when the fields drop).
+    unsafe { SDL_UnlockTexture(self.texture) }
+  }
+}
+
+/// A CPU side surface paired with a streaming texture of the same size.
+///
+/// This packages up the "draw pixels on the CPU, then show them with the GPU"
+/// loop. Each frame you edit the [surface](Framebuffer::surface) however you
+/// like, then call [present](Framebuffer::present) to upload the pixels and
+/// copy them to the current render target.
+///
+/// Make one with [create_framebuffer](Renderer::create_framebuffer).
+///
+/// The surface keeps its pixels between frames, unlike a plain
+/// [lock_to_surface](Texture::lock_to_surface), so you only have to redraw
+/// the parts that change.
+#[derive(Debug)]
+pub struct Framebuffer<'sdl, 'win, 'ren> {
+  pub(crate) texture: Texture<'sdl, 'win, 'ren>,
+  pub(crate) surface: Surface<'sdl>,
+}
+impl<'sdl, 'win, 'ren> Framebuffer<'sdl, 'win, 'ren> {
+  /// The surface to draw into.
+  pub fn surface(&mut self) -> &mut Surface<'sdl> {
+    &mut self.surface
+  }
+
+  /// The streaming texture that the surface is uploaded to.
+  pub fn texture(&self) -> &Texture<'sdl, 'win, 'ren> {
+    &self.texture
+  }
+
+  /// Uploads the surface to the texture and copies it to the render target.
+  ///
+  /// * `dst`: Where to draw the framebuffer. If None, it's stretched over the
+  ///   entire target.
+  ///
+  /// The renderer should be the one that made this framebuffer.
+  pub fn present(&self, renderer: &Renderer, dst: Option<Rect>) -> Result<(), String> {
+    // Note: the surface is never RLE encoded, so the pixels are always
+    // available and we don't need to lock it.
+    let out = unsafe {
+      SDL_UpdateTexture(
+        self.texture.ptr,
+        null(),
+        (*self.surface.ptr).pixels,
+        (*self.surface.ptr).pitch,
+      )
+    };
+    if out != 0 {
+      return Err(get_error());
+    }
+    renderer.copy(&self.texture, None, dst)
+  }
+}
+
+/// How a texture is expected to be used.
+///
+/// This is a usage hint given to the driver when the texture is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(windows, repr(i32))]
+#[cfg_attr(not(windows), repr(u32))]
+pub enum TextureAccess {
+  /// Changes rarely, not lockable.
+  Static = SDL_TEXTUREACCESS_STATIC,
+  /// Changes frequently, lockable.
+  Streaming = SDL_TEXTUREACCESS_STREAMING,
+  /// Can be used as a render target.
+  Target = SDL_TEXTUREACCESS_TARGET,
+}
+
+/// Everything SDL2 knows about a texture's setup.
+///
+/// See [query](Texture::query).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub struct TextureInfo {
+  pub format: PixelFormatEnum,
+  pub access: TextureAccess,
+  pub width: i32,
+  pub height: i32,
+}
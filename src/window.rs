@@ -58,6 +58,344 @@ pub const WINDOW_POSITION_CENTERED: i32 = SDL_WINDOWPOS_CENTERED_MASK as i32;
 /// See [create_window](SDLToken::create_window).
 pub const WINDOW_POSITION_UNDEFINED: i32 = SDL_WINDOWPOS_UNDEFINED_MASK as i32;
 
+impl SDLToken {
+  /// Starts building a new window.
+  ///
+  /// This is an alternative to [create_window](SDLToken::create_window) that
+  /// lets you name each property instead of packing up the `WindowFlags`.
+  pub fn window_builder(&self) -> WindowBuilder<'_> {
+    WindowBuilder {
+      sdl: self,
+      title: String::new(),
+      x: WINDOW_POSITION_CENTERED,
+      y: WINDOW_POSITION_CENTERED,
+      w: 800,
+      h: 600,
+      flags: WindowFlags::default(),
+      shaped: false,
+    }
+  }
+
+  /// Creates a shaped window, or gives an error message.
+  ///
+  /// This is like [create_window](SDLToken::create_window), but the window
+  /// can later be given a non-rectangular shape with
+  /// [set_shape](Window::set_shape). A window can only be shaped if it was
+  /// made this way. Shaped windows are always borderless, can't be resized,
+  /// and start out hidden until the shape is set.
+  pub fn create_shaped_window<'sdl>(
+    &'sdl self, title: &str, x: i32, y: i32, w: i32, h: i32, flags: WindowFlags,
+  ) -> Result<Window<'sdl>, String> {
+    self.assert_main_thread();
+    let title_null: Vec<u8> = title.bytes().chain(Some(0)).collect();
+    let ptr = unsafe {
+      SDL_CreateShapedWindow(
+        title_null.as_ptr() as *const c_char,
+        x as u32,
+        y as u32,
+        w as u32,
+        h as u32,
+        flags.0 as _,
+      )
+    };
+    if ptr.is_null() {
+      Err(get_error())
+    } else {
+      Ok(Window {
+        ptr,
+        _marker: PhantomData,
+      })
+    }
+  }
+
+  /// Finds the display mode of a display that's the closest match to the mode
+  /// given.
+  ///
+  /// The format and refresh rate default to the desktop's if they're 0 in the
+  /// desired mode. The size is matched first, then the format, then the
+  /// refresh rate. If no mode is at least as large as the desired size you get
+  /// `None`.
+  pub fn closest_display_mode(&self, display: i32, desired: DisplayMode) -> Option<DisplayMode> {
+    let desired: SDL_DisplayMode = desired.into();
+    let mut closest = SDL_DisplayMode::default();
+    let ptr = unsafe { SDL_GetClosestDisplayMode(display, &desired, &mut closest) };
+    if ptr.is_null() {
+      None
+    } else {
+      Some(DisplayMode::from(closest))
+    }
+  }
+
+  /// Sets if X11 windows ask the compositor to get out of their way.
+  ///
+  /// By default SDL2 sets `_NET_WM_BYPASS_COMPOSITOR` on its windows, which
+  /// lets the compositor stop compositing a fullscreen window. That's usually
+  /// faster, but with some compositors it causes stuttering, broken vsync, or
+  /// trouble when alt-tabbing, and passing `false` here turns it off.
+  ///
+  /// SDL2 only reads this when a window is _made_, so call this before you
+  /// make your windows (it doesn't affect windows that already exist). On
+  /// anything other than X11 it does nothing.
+  ///
+  /// This is a normal priority hint, so if the user has set the
+  /// `SDL_VIDEO_X11_NET_WM_BYPASS_COMPOSITOR` environment variable that wins
+  /// and you get an error.
+  pub fn set_bypass_compositor(&self, bypass: bool) -> Result<(), String> {
+    let name = SDL_HINT_VIDEO_X11_NET_WM_BYPASS_COMPOSITOR.as_ptr() as *const c_char;
+    let value: &[u8] = if bypass { b"1\0" } else { b"0\0" };
+    if SDL_TRUE == unsafe { SDL_SetHint(name, value.as_ptr() as *const c_char) } {
+      Ok(())
+    } else {
+      Err("beryllium error: the bypass compositor hint is overridden.".to_string())
+    }
+  }
+
+  /// Gets the number of displays (monitors) available.
+  pub fn num_displays(&self) -> Result<i32, String> {
+    let out = unsafe { SDL_GetNumVideoDisplays() };
+    if out < 0 {
+      Err(get_error())
+    } else {
+      Ok(out)
+    }
+  }
+
+  /// Gets the area of a display, in the global screen coordinate space.
+  ///
+  /// The primary display is at (0, 0).
+  pub fn display_bounds(&self, display: i32) -> Result<Rect, String> {
+    display_bounds(display)
+  }
+
+  /// Gets the DPI values of a display.
+  ///
+  /// Not all platforms can report this, in which case you get an error.
+  pub fn display_dpi(&self, display: i32) -> Result<DisplayDpi, String> {
+    let mut dpi = DisplayDpi::default();
+    let out = unsafe { SDL_GetDisplayDPI(display, &mut dpi.ddpi, &mut dpi.hdpi, &mut dpi.vdpi) };
+    if out == 0 {
+      Ok(dpi)
+    } else {
+      Err(get_error())
+    }
+  }
+}
+
+/// Gets the area of a display.
+fn display_bounds(display: i32) -> Result<Rect, String> {
+  let mut rect = Rect::default();
+  let out = unsafe { SDL_GetDisplayBounds(display, &mut rect as *mut Rect as *mut SDL_Rect) };
+  if out == 0 {
+    Ok(rect)
+  } else {
+    Err(get_error())
+  }
+}
+
+/// Checks that a display index is in range.
+fn check_display_index(display: i32) -> Result<(), String> {
+  let count = unsafe { SDL_GetNumVideoDisplays() };
+  if count < 0 {
+    Err(get_error())
+  } else if display < 0 || display >= count {
+    Err("beryllium error: display index out of range.".to_string())
+  } else {
+    Ok(())
+  }
+}
+
+/// The DPI values of a display.
+///
+/// See [display_dpi](SDLToken::display_dpi).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct DisplayDpi {
+  /// Diagonal DPI.
+  pub ddpi: f32,
+  /// Horizontal DPI.
+  pub hdpi: f32,
+  /// Vertical DPI.
+  pub vdpi: f32,
+}
+
+/// Collects up all the settings for a new window.
+///
+/// See [window_builder](SDLToken::window_builder). By default the window is
+/// 800x600, centered on the screen, and has no other flags set.
+///
+/// Note that the `opengl` and `vulkan` flags can only be given when the window
+/// is created, you can't turn them on later and then make a GL context or
+/// Vulkan surface.
+#[derive(Debug, Clone)]
+pub struct WindowBuilder<'sdl> {
+  sdl: &'sdl SDLToken,
+  title: String,
+  x: i32,
+  y: i32,
+  w: i32,
+  h: i32,
+  flags: WindowFlags,
+  shaped: bool,
+}
+impl<'sdl> WindowBuilder<'sdl> {
+  /// The title of the window.
+  pub fn title(mut self, title: &str) -> Self {
+    self.title = title.to_string();
+    self
+  }
+
+  /// The position of the window.
+  ///
+  /// You can use [WINDOW_POSITION_CENTERED] or [WINDOW_POSITION_UNDEFINED]
+  /// for either axis.
+  pub fn position(mut self, x: i32, y: i32) -> Self {
+    self.x = x;
+    self.y = y;
+    self
+  }
+
+  /// The size of the window, in screen coordinates.
+  pub fn size(mut self, w: i32, h: i32) -> Self {
+    self.w = w;
+    self.h = h;
+    self
+  }
+
+  /// If the user can resize the window.
+  pub fn resizable(mut self, resizable: bool) -> Self {
+    self.flags = self.flags.with_resizable(resizable);
+    self
+  }
+
+  /// If the window should be made in (real) fullscreen mode.
+  pub fn fullscreen(mut self, fullscreen: bool) -> Self {
+    self.flags = self.flags.with_fullscreen(fullscreen);
+    self
+  }
+
+  /// If the window should have no decorations.
+  pub fn borderless(mut self, borderless: bool) -> Self {
+    self.flags = self.flags.with_borderless(borderless);
+    self
+  }
+
+  /// If the window should be created in high-DPI mode if supported.
+  ///
+  /// See [WindowFlags::allow_high_dpi].
+  pub fn allow_highdpi(mut self, allow_highdpi: bool) -> Self {
+    self.flags = self.flags.with_allow_high_dpi(allow_highdpi);
+    self
+  }
+
+  /// If the window should be usable with OpenGL.
+  pub fn opengl(mut self, opengl: bool) -> Self {
+    self.flags = self.flags.with_opengl(opengl);
+    self
+  }
+
+  /// If the window should be usable with Vulkan.
+  pub fn vulkan(mut self, vulkan: bool) -> Self {
+    self.flags = self.flags.with_vulkan(vulkan);
+    self
+  }
+
+  /// If the window should start out hidden.
+  pub fn hidden(mut self, hidden: bool) -> Self {
+    self.flags = self.flags.with_hidden(hidden);
+    self
+  }
+
+  /// If the window should be a shaped window.
+  ///
+  /// See [create_shaped_window](SDLToken::create_shaped_window), this has to
+  /// be picked when the window is made.
+  pub fn shaped(mut self, shaped: bool) -> Self {
+    self.shaped = shaped;
+    self
+  }
+
+  /// Creates the window, or gives an error message.
+  pub fn build(self) -> Result<Window<'sdl>, String> {
+    if self.shaped {
+      self
+        .sdl
+        .create_shaped_window(&self.title, self.x, self.y, self.w, self.h, self.flags)
+    } else {
+      self
+        .sdl
+        .create_window(&self.title, self.x, self.y, self.w, self.h, self.flags)
+    }
+  }
+}
+
+/// How a surface is turned into the shape of a window.
+///
+/// See [set_shape](Window::set_shape).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeMode {
+  /// Pixels with any alpha at all are part of the window.
+  Default,
+  /// Pixels with alpha of at least the cutoff are part of the window.
+  BinarizeAlpha(u8),
+  /// Pixels with alpha below the cutoff are part of the window.
+  ReverseBinarizeAlpha(u8),
+  /// Pixels that aren't the key color are part of the window.
+  ColorKey(Color),
+}
+impl From<ShapeMode> for SDL_WindowShapeMode {
+  fn from(mode: ShapeMode) -> Self {
+    use fermium::WindowShapeMode::*;
+    let (mode, parameters) = match mode {
+      ShapeMode::Default => (
+        ShapeModeDefault,
+        SDL_WindowShapeParams {
+          binarizationCutoff: 1,
+        },
+      ),
+      ShapeMode::BinarizeAlpha(cutoff) => (
+        ShapeModeBinarizeAlpha,
+        SDL_WindowShapeParams {
+          binarizationCutoff: cutoff,
+        },
+      ),
+      ShapeMode::ReverseBinarizeAlpha(cutoff) => (
+        ShapeModeReverseBinarizeAlpha,
+        SDL_WindowShapeParams {
+          binarizationCutoff: cutoff,
+        },
+      ),
+      ShapeMode::ColorKey(color) => (
+        ShapeModeColorKey,
+        SDL_WindowShapeParams {
+          colorKey: SDL_Color::from(color),
+        },
+      ),
+    };
+    SDL_WindowShapeMode { mode, parameters }
+  }
+}
+
+/// The native windowing system handles of a window.
+///
+/// See [wm_info](Window::wm_info). All of the pointers are owned by SDL2 and
+/// only stay valid as long as the window does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum WMInfo {
+  /// A Win32 window.
+  Windows { hwnd: *mut c_void },
+  /// A macOS `NSWindow`.
+  Cocoa { window: *mut c_void },
+  /// An X11 `Display*` and `Window`.
+  X11 { display: *mut c_void, window: u64 },
+  /// A Wayland `wl_display*` and `wl_surface*`.
+  Wayland {
+    display: *mut c_void,
+    surface: *mut c_void,
+  },
+  /// Some other windowing system, by its `SDL_SYSWM_TYPE` value.
+  Other { subsystem: u32 },
+}
+
 /// Handle to a window on the screen.
 #[derive(Debug)]
 #[repr(transparent)]
@@ -71,6 +409,59 @@ impl<'sdl> Drop for Window<'sdl> {
   }
 }
 impl<'sdl> Window<'sdl> {
+  /// Gets the native windowing system handles for this window.
+  ///
+  /// This is the escape hatch for using the window with native OS APIs or
+  /// other libraries (such as `raw-window-handle`). The subsystems that this
+  /// build of SDL2 can't report on give [Other](WMInfo::Other).
+  pub fn wm_info(&self) -> Result<WMInfo, String> {
+    let mut info = SDL_SysWMinfo {
+      version: SDL_version {
+        major: SDL_MAJOR_VERSION as u8,
+        minor: SDL_MINOR_VERSION as u8,
+        patch: SDL_PATCHLEVEL as u8,
+      },
+      ..SDL_SysWMinfo::default()
+    };
+    if SDL_TRUE != unsafe { SDL_GetWindowWMInfo(self.ptr, &mut info) } {
+      return Err(get_error());
+    }
+    use fermium::SDL_SYSWM_TYPE::*;
+    Ok(match info.subsystem {
+      #[cfg(windows)]
+      SDL_SYSWM_WINDOWS => WMInfo::Windows {
+        hwnd: unsafe { info.info.win.window } as *mut c_void,
+      },
+      #[cfg(target_os = "macos")]
+      SDL_SYSWM_COCOA => WMInfo::Cocoa {
+        window: unsafe { info.info.cocoa.window } as *mut c_void,
+      },
+      #[cfg(all(unix, not(target_os = "macos")))]
+      SDL_SYSWM_X11 => unsafe {
+        WMInfo::X11 {
+          display: info.info.x11.display as *mut c_void,
+          window: info.info.x11.window as _,
+        }
+      },
+      #[cfg(all(unix, not(target_os = "macos")))]
+      SDL_SYSWM_WAYLAND => unsafe {
+        WMInfo::Wayland {
+          display: info.info.wl.display as *mut c_void,
+          surface: info.info.wl.surface as *mut c_void,
+        }
+      },
+      subsystem => WMInfo::Other { subsystem },
+    })
+  }
+
+  /// Gets the raw `*mut SDL_Window` pointer, for use with other C libraries.
+  ///
+  /// The window is still owned by this value: don't free the pointer, and don't
+  /// use it after this value has been dropped.
+  pub fn as_raw(&self) -> *mut SDL_Window {
+    self.ptr
+  }
+
   /// Like the [lone_message_box](lone_message_box) function, but
   /// modal to this `Window`.
   ///
@@ -96,6 +487,48 @@ impl<'sdl> Window<'sdl> {
     }
   }
 
+  /// Makes this window a modal dialog for the `parent` window.
+  ///
+  /// Currently this is only supported by the X11 backend, other platforms will
+  /// give you an error. Both windows must stay open for the relationship to
+  /// make sense.
+  ///
+  /// (SDL 2.0.5 or later)
+  pub fn set_modal_for(&self, parent: &Window) -> Result<(), String> {
+    let out = unsafe { SDL_SetWindowModalFor(self.ptr, parent.ptr) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// The ID of this window.
+  ///
+  /// Every window event says which window it's for with this value, so if you
+  /// have more than one window this is how you tell them apart.
+  pub fn id(&self) -> Result<u32, String> {
+    let out = unsafe { SDL_GetWindowID(self.ptr) };
+    if out == 0 {
+      Err(get_error())
+    } else {
+      Ok(out)
+    }
+  }
+
+  /// Shows the window, if it's hidden.
+  pub fn show(&self) {
+    unsafe { SDL_ShowWindow(self.ptr) }
+  }
+
+  /// Hides the window.
+  ///
+  /// The window still exists, so its renderer and textures stay valid, it's
+  /// just not on the screen.
+  pub fn hide(&self) {
+    unsafe { SDL_HideWindow(self.ptr) }
+  }
+
   /// Makes a renderer for the window.
   ///
   /// # Safety
@@ -109,14 +542,49 @@ impl<'sdl> Window<'sdl> {
     &'win self, driver_index: Option<usize>, flags: RendererFlags,
   ) -> Result<Renderer<'sdl, 'win>, String> {
     let index = driver_index.map(|u| u as i32).unwrap_or(-1);
+    debug_assert_init_thread();
     let ptr = SDL_CreateRenderer(self.ptr, index, flags.0 as u32);
     if ptr.is_null() {
       Err(get_error())
     } else {
-      Ok(Renderer {
+      let renderer = Renderer {
         ptr,
+        cached_output_size: Cell::new((0, 0)),
+        draw_color_stack: RefCell::new(Vec::new()),
+        stretch_size: Cell::new(None),
         _marker: PhantomData,
-      })
+      };
+      renderer.on_resize()?;
+      Ok(renderer)
+    }
+  }
+
+  /// Makes a renderer for the window using the render driver named.
+  ///
+  /// The names are the ones in [render_drivers](SDLToken::render_drivers)
+  /// (such as `"opengl"`, `"direct3d11"`, or `"software"`). This gives an
+  /// error if there's no driver by that name, rather than quietly picking a
+  /// different one.
+  ///
+  /// # Safety
+  ///
+  /// The same as [create_renderer](Window::create_renderer).
+  pub unsafe fn create_renderer_with_driver<'win>(
+    &'win self, driver: &str, flags: RendererFlags,
+  ) -> Result<Renderer<'sdl, 'win>, String> {
+    let count = SDL_GetNumRenderDrivers();
+    let index = (0..count.max(0)).find(|&index| {
+      let mut info = SDL_RendererInfo::default();
+      SDL_GetRenderDriverInfo(index, &mut info) == 0
+        && !info.name.is_null()
+        && gather_string(info.name) == driver
+    });
+    match index {
+      Some(index) => self.create_renderer(Some(index as usize), flags),
+      None => Err(format!(
+        "beryllium error: there's no render driver named {:?}.",
+        driver
+      )),
     }
   }
 
@@ -139,6 +607,74 @@ impl<'sdl> Window<'sdl> {
     unsafe { SDL_SetWindowSize(self.ptr, width, height) }
   }
 
+  /// Gets the position of the window's upper left corner (in screen
+  /// coordinates).
+  pub fn position(&self) -> (i32, i32) {
+    let mut x = 0;
+    let mut y = 0;
+    unsafe { SDL_GetWindowPosition(self.ptr, &mut x, &mut y) };
+    (x, y)
+  }
+
+  /// Gets where the mouse cursor is, relative to this window's upper left
+  /// corner.
+  ///
+  /// Unlike [mouse_state](SDLToken::mouse_state) this works for any window,
+  /// not just the one with mouse focus. The position isn't clamped, so it's
+  /// negative or past the window's size when the cursor is outside of the
+  /// window. Like [global_mouse_state](SDLToken::global_mouse_state) this is
+  /// the "right now" position.
+  pub fn relative_mouse_position(&self) -> Point {
+    let mut x = 0;
+    let mut y = 0;
+    unsafe { SDL_GetGlobalMouseState(&mut x, &mut y) };
+    let (win_x, win_y) = self.position();
+    Point {
+      x: x - win_x,
+      y: y - win_y,
+    }
+  }
+
+  /// Sets the position of the window's upper left corner.
+  ///
+  /// You can use [WINDOW_POSITION_CENTERED] or [WINDOW_POSITION_UNDEFINED]
+  /// for either axis.
+  pub fn set_position(&self, x: i32, y: i32) {
+    unsafe { SDL_SetWindowPosition(self.ptr, x, y) }
+  }
+
+  /// Centers the window on the display given.
+  pub fn center_on_display(&self, display: i32) -> Result<(), String> {
+    check_display_index(display)?;
+    // Note: this is what the `SDL_WINDOWPOS_CENTERED_DISPLAY` macro does.
+    let centered = WINDOW_POSITION_CENTERED | display;
+    self.set_position(centered, centered);
+    Ok(())
+  }
+
+  /// Moves the window's upper left corner to the upper left of the display
+  /// given.
+  pub fn move_to_display(&self, display: i32) -> Result<(), String> {
+    check_display_index(display)?;
+    let bounds = display_bounds(display)?;
+    self.set_position(bounds.x, bounds.y);
+    Ok(())
+  }
+
+  /// Sets if the window grabs input, confining the mouse to the window.
+  ///
+  /// If another window already has the grab, that window loses it. The
+  /// whole window is the confining area.
+  pub fn set_grab(&self, grabbed: bool) {
+    let grabbed = if grabbed { SDL_TRUE } else { SDL_FALSE };
+    unsafe { SDL_SetWindowGrab(self.ptr, grabbed) }
+  }
+
+  /// Checks if the window currently grabs input.
+  pub fn is_grabbed(&self) -> bool {
+    SDL_TRUE == unsafe { SDL_GetWindowGrab(self.ptr) }
+  }
+
   /// Obtains info about the fullscreen settings of the window.
   pub fn display_mode(&self) -> Result<DisplayMode, String> {
     let mut mode = SDL_DisplayMode::default();
@@ -155,6 +691,11 @@ impl<'sdl> Window<'sdl> {
   /// * If `Some(mode)`, attempts to set the mode given.
   /// * If `None`, it will use the window's dimensions, and the desktop's
   ///   current format and refresh rate.
+  ///
+  /// This only takes effect when the window is in (real) fullscreen, so to go
+  /// fullscreen at a particular resolution you'd pick a mode (perhaps with
+  /// [closest_display_mode](SDLToken::closest_display_mode)), set it with
+  /// this, and then call [set_fullscreen_style](Window::set_fullscreen_style).
   pub fn set_display_mode(&self, opt_mode: Option<DisplayMode>) -> Result<(), String> {
     let out = match opt_mode {
       Some(mode) => {
@@ -227,6 +768,172 @@ impl<'sdl> Window<'sdl> {
       Err(get_error())
     }
   }
+
+  /// Gets the names of the Vulkan instance extensions needed to make a
+  /// surface for this window.
+  ///
+  /// Pass these along when you create your `VkInstance`. The window must have
+  /// been created with the `vulkan` flag.
+  pub fn vulkan_instance_extensions(&self) -> Result<Vec<String>, String> {
+    let mut count = 0;
+    let out = unsafe { SDL_Vulkan_GetInstanceExtensions(self.ptr, &mut count, null_mut()) };
+    if out != SDL_TRUE {
+      return Err(get_error());
+    }
+    let mut names: Vec<*const c_char> = vec![null(); count as usize];
+    let out = unsafe { SDL_Vulkan_GetInstanceExtensions(self.ptr, &mut count, names.as_mut_ptr()) };
+    if out == SDL_TRUE {
+      Ok(
+        names
+          .iter()
+          .take(count as usize)
+          .map(|&ptr| unsafe { gather_string(ptr) })
+          .collect(),
+      )
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Creates a Vulkan rendering surface for this window.
+  ///
+  /// The window must have been created with the `vulkan` flag, and the
+  /// instance must have been created with the extensions from
+  /// [vulkan_instance_extensions](Window::vulkan_instance_extensions)
+  /// enabled. Destroying the surface is up to you (with `vkDestroySurfaceKHR`),
+  /// and it should be done before the window is dropped.
+  ///
+  /// # Safety
+  ///
+  /// * `instance` must be a valid `VkInstance` handle.
+  pub unsafe fn vulkan_create_surface(&self, instance: VkInstance) -> Result<VkSurfaceKHR, String> {
+    let mut surface: VkSurfaceKHR = null_mut();
+    let out = SDL_Vulkan_CreateSurface(self.ptr, instance, &mut surface);
+    if out == SDL_TRUE {
+      Ok(surface)
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Obtains the size of the Vulkan drawable space in the window.
+  ///
+  /// This is the "physical pixels" size, and it's what you should use for the
+  /// swapchain extent. It can differ from [size](Window::size) when the
+  /// window is high-DPI.
+  pub fn vulkan_get_drawable_size(&self) -> (i32, i32) {
+    let mut w = 0;
+    let mut h = 0;
+    unsafe { SDL_Vulkan_GetDrawableSize(self.ptr, &mut w, &mut h) };
+    (w, h)
+  }
+
+  /// Checks if this is a shaped window.
+  ///
+  /// Only windows made with
+  /// [create_shaped_window](SDLToken::create_shaped_window) (or a builder
+  /// with [shaped](WindowBuilder::shaped) set) are shaped.
+  pub fn is_shaped_window(&self) -> bool {
+    SDL_TRUE == unsafe { SDL_IsShapedWindow(self.ptr) }
+  }
+
+  /// Sets the shape of a shaped window.
+  ///
+  /// The surface is the same size as the window, and `mode` says which of its
+  /// pixels are part of the window. This fails if the window isn't a
+  /// [shaped window](Window::is_shaped_window).
+  pub fn set_shape(&self, surface: &Surface, mode: ShapeMode) -> Result<(), String> {
+    let mut shape_mode = SDL_WindowShapeMode::from(mode);
+    let out = unsafe { SDL_SetWindowShape(self.ptr, surface.ptr, &mut shape_mode) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets the window's software framebuffer as a surface.
+  ///
+  /// You can edit the pixels of this surface and then use
+  /// [update](WindowSurface::update) to show them in the window. This is the
+  /// "software rendering" path, so don't also make a [Renderer] for the same
+  /// window.
+  ///
+  /// The surface is owned by the window, and SDL2 frees it and makes a new
+  /// one the next time this is called after the window changes size. So the
+  /// `WindowSurface` keeps the window mutably borrowed: you can't call
+  /// [set_size](Window::set_size) or get the surface again until it's
+  /// dropped. If the user resizes the window (you'll get a
+  /// [WindowEvent::SizeChanged] event) the pixels stay valid, but
+  /// [update](WindowSurface::update) gives an error until you drop the
+  /// `WindowSurface` and call this method again to get a fresh one.
+  pub fn surface(&mut self) -> Result<WindowSurface<'_>, String> {
+    let ptr = unsafe { SDL_GetWindowSurface(self.ptr) };
+    if ptr.is_null() {
+      Err(get_error())
+    } else {
+      Ok(WindowSurface {
+        surface: ManuallyDrop::new(Surface {
+          ptr,
+          _marker: PhantomData,
+        }),
+        window: self.ptr,
+        _marker: PhantomData,
+      })
+    }
+  }
+}
+
+/// The software framebuffer of a [Window](Window::surface).
+///
+/// This derefs to [Surface], so all the usual surface editing methods work.
+/// The window owns the actual memory, so dropping this doesn't free anything.
+///
+/// The window stays mutably borrowed while this is alive, so that it can't
+/// be resized or asked for a new surface (either of which would free this
+/// one's pixels).
+#[derive(Debug)]
+pub struct WindowSurface<'win> {
+  surface: ManuallyDrop<Surface<'win>>,
+  window: *mut SDL_Window,
+  _marker: PhantomData<&'win mut SDL_Window>,
+}
+impl<'win> Deref for WindowSurface<'win> {
+  type Target = Surface<'win>;
+  fn deref(&self) -> &Surface<'win> {
+    &self.surface
+  }
+}
+impl<'win> DerefMut for WindowSurface<'win> {
+  fn deref_mut(&mut self) -> &mut Surface<'win> {
+    &mut self.surface
+  }
+}
+impl<'win> WindowSurface<'win> {
+  /// Copies the entire surface to the screen.
+  pub fn update(&self) -> Result<(), String> {
+    let out = unsafe { SDL_UpdateWindowSurface(self.window) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Copies just the given areas of the surface to the screen.
+  pub fn update_rects(&self, rects: &[Rect]) -> Result<(), String> {
+    if rects.len() > i32::MAX as usize {
+      return Err("beryllium error: len cannot exceed `i32::MAX`.".to_string());
+    }
+    let ptr = rects.as_ptr() as *const SDL_Rect;
+    let count = rects.len() as i32;
+    let out = unsafe { SDL_UpdateWindowSurfaceRects(self.window, ptr, count) };
+    if out == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
 }
 
 /// The window's fullscreen style.
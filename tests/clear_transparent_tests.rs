@@ -0,0 +1,49 @@
+//! Checks that `clear_transparent` really gives alpha 0, and that it puts
+//! the renderer's settings back.
+
+use beryllium::*;
+
+mod common;
+
+#[test]
+fn test_clear_transparent_readback() {
+  // RGBA8888, so that the surface keeps the alpha channel.
+  let format = SurfaceFormat::Direct32 {
+    r_mask: 0xFF00_0000,
+    g_mask: 0x00FF_0000,
+    b_mask: 0x0000_FF00,
+    a_mask: 0x0000_00FF,
+  };
+  common::with_surface_renderer(4, 4, format, |_sdl, surface, renderer| {
+    let target = renderer
+      .create_texture(PixelFormatEnum::RGBA8888, TextureAccess::Target, 4, 4)
+      .unwrap();
+
+    renderer.set_draw_color(Color::RED).unwrap();
+    renderer.set_draw_blend_mode(BlendMode::Blend).unwrap();
+    {
+      let _guard = renderer.push_render_target(&target).unwrap();
+      renderer.clear().unwrap();
+      renderer.clear_transparent().unwrap();
+    }
+    assert_eq!(renderer.draw_color().unwrap(), Color::RED);
+    assert_eq!(renderer.draw_blend_mode().unwrap(), BlendMode::Blend);
+
+    // Fill the surface with opaque red, then copy the texture over it without
+    // blending, so that the texture's alpha ends up in the surface.
+    renderer.clear().unwrap();
+    target.set_blend_mode(BlendMode::None).unwrap();
+    renderer.copy(&target, None, None).unwrap();
+    for y in 0..4 {
+      for x in 0..4 {
+        assert_eq!(
+          surface.get_pixel(x, y).unwrap().a,
+          0,
+          "pixel ({}, {})",
+          x,
+          y
+        );
+      }
+    }
+  });
+}
@@ -0,0 +1,75 @@
+use beryllium::*;
+
+#[test]
+fn test_color_constructors() {
+  assert_eq!(
+    Color::rgb(1, 2, 3),
+    Color {
+      r: 1,
+      g: 2,
+      b: 3,
+      a: 255
+    }
+  );
+  assert_eq!(
+    Color::rgba(1, 2, 3, 4),
+    Color {
+      r: 1,
+      g: 2,
+      b: 3,
+      a: 4
+    }
+  );
+}
+
+#[test]
+fn test_color_constants() {
+  assert_eq!(Color::WHITE, Color::rgba(255, 255, 255, 255));
+  assert_eq!(Color::BLACK, Color::rgba(0, 0, 0, 255));
+  assert_eq!(Color::RED, Color::rgba(255, 0, 0, 255));
+  assert_eq!(Color::GREEN, Color::rgba(0, 255, 0, 255));
+  assert_eq!(Color::BLUE, Color::rgba(0, 0, 255, 255));
+  assert_eq!(Color::YELLOW, Color::rgba(255, 255, 0, 255));
+  assert_eq!(Color::CYAN, Color::rgba(0, 255, 255, 255));
+  assert_eq!(Color::MAGENTA, Color::rgba(255, 0, 255, 255));
+  assert_eq!(Color::TRANSPARENT, Color::rgba(0, 0, 0, 0));
+}
+
+#[test]
+fn test_color_inverted() {
+  assert_eq!(
+    Color::rgba(0, 100, 255, 7).inverted(),
+    Color::rgba(255, 155, 0, 7)
+  );
+  assert_eq!(Color::WHITE.inverted(), Color::BLACK);
+}
+
+#[test]
+fn test_color_grayscale() {
+  assert_eq!(Color::WHITE.grayscale(), Color::WHITE);
+  assert_eq!(Color::BLACK.grayscale(), Color::BLACK);
+  assert_eq!(Color::RED.grayscale(), Color::rgb(76, 76, 76));
+  assert_eq!(
+    Color::rgba(0, 255, 0, 9).grayscale(),
+    Color::rgba(150, 150, 150, 9)
+  );
+}
+
+#[test]
+fn test_color_linear_round_trip() {
+  for v in 0..=255 {
+    let c = Color::rgba(v, v, v, v);
+    assert_eq!(c.to_linear().to_srgb(), c);
+  }
+}
+
+#[test]
+fn test_color_blend_linear() {
+  assert_eq!(Color::BLACK.blend_linear(Color::WHITE, 0.0), Color::BLACK);
+  assert_eq!(Color::BLACK.blend_linear(Color::WHITE, 1.0), Color::WHITE);
+  // Half way in linear light is much brighter than the naive 128.
+  let mid = Color::BLACK.blend_linear(Color::WHITE, 0.5);
+  assert_eq!(mid, Color::rgb(188, 188, 188));
+  let fade = Color::RED.blend_linear(Color::TRANSPARENT, 0.5);
+  assert_eq!(fade.a, 128);
+}
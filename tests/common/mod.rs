@@ -0,0 +1,25 @@
+//! Setup for the tests that need SDL2 itself.
+//!
+//! SDL2 can only be initialized once per process, so each test that uses this
+//! module is in its own test binary and has just the one `#[test]`.
+
+#![allow(dead_code)]
+
+use beryllium::*;
+
+/// Initializes SDL2, panicking if that fails.
+pub fn init_sdl() -> SDLToken {
+  unsafe { init().expect("Couldn't initialize SDL2!") }
+}
+
+/// Initializes SDL2 and passes `f` a `w` by `h` surface along with a software
+/// renderer that draws to it.
+pub fn with_surface_renderer<F>(w: i32, h: i32, format: SurfaceFormat, f: F)
+where
+  F: FnOnce(&SDLToken, &Surface<'_>, &Renderer<'_, '_>),
+{
+  let sdl = init_sdl();
+  let surface = sdl.create_rgb_surface(w, h, format).unwrap();
+  let renderer = Renderer::from_surface(&surface).unwrap();
+  f(&sdl, &surface, &renderer);
+}
@@ -0,0 +1,41 @@
+use beryllium::*;
+use core::f32::consts::FRAC_1_SQRT_2;
+
+#[test]
+fn test_apply_deadzone() {
+  assert_eq!(apply_deadzone(0, 8000), 0.0);
+  assert_eq!(apply_deadzone(8000, 8000), 0.0);
+  assert_eq!(apply_deadzone(-8000, 8000), 0.0);
+  assert_eq!(apply_deadzone(i16::MAX, 8000), 1.0);
+  assert_eq!(apply_deadzone(i16::MIN, 8000), -1.0);
+  let half = apply_deadzone(8000 + (i16::MAX - 8000) / 2, 8000);
+  assert!((half - 0.5).abs() < 0.001);
+  assert!(apply_deadzone(-12000, 8000) < 0.0);
+  assert_eq!(apply_deadzone(i16::MAX, 0), 1.0);
+}
+
+#[test]
+fn test_apply_stick_deadzone() {
+  assert_eq!(apply_stick_deadzone(5000, 5000, 8000), (0.0, 0.0));
+  let (x, y) = apply_stick_deadzone(i16::MAX, 0, 8000);
+  assert_eq!((x, y), (1.0, 0.0));
+  let (x, y) = apply_stick_deadzone(i16::MIN, i16::MIN, 8000);
+  assert!((x + FRAC_1_SQRT_2).abs() < 0.001);
+  assert!((y + FRAC_1_SQRT_2).abs() < 0.001);
+  let (x, y) = apply_stick_deadzone(0, -20000, 8000);
+  assert_eq!(x, 0.0);
+  assert!(y < 0.0 && y > -1.0);
+}
+
+#[test]
+fn test_guid_string_round_trip() {
+  let guid = [
+    0x03, 0x00, 0x00, 0x00, 0x5e, 0x04, 0x00, 0x00, 0x8e, 0x02, 0x00, 0x00, 0x14, 0x01, 0x00, 0x00,
+  ];
+  let s = guid_to_string(guid);
+  assert_eq!(s, "030000005e0400008e02000014010000");
+  assert_eq!(guid_from_string(&s), guid);
+  assert_eq!(guid_from_string("030000005E0400008E02000014010000"), guid);
+  assert_eq!(guid_from_string(""), [0; 16]);
+  assert_eq!(guid_from_string("zz01")[..2], [0x00, 0x01]);
+}
@@ -0,0 +1,58 @@
+//! Checks that `draw_line_batch` draws the same pixels as one `draw_line`
+//! call per segment.
+
+use beryllium::*;
+
+mod common;
+
+#[test]
+fn test_draw_line_batch_vs_naive() {
+  const SIZE: i32 = 512;
+  const STEP: i32 = 4;
+
+  // A zig-zag walk over a grid, so that the segments connect end to end.
+  let mut segments = Vec::new();
+  for row in 0..(SIZE / STEP) {
+    let y = row * STEP;
+    let (a, b) = if row % 2 == 0 {
+      (0, SIZE - 1)
+    } else {
+      (SIZE - 1, 0)
+    };
+    segments.push((Point { x: a, y }, Point { x: b, y }));
+    segments.push((Point { x: b, y }, Point { x: b, y: y + STEP }));
+  }
+
+  common::with_surface_renderer(
+    SIZE,
+    SIZE,
+    SurfaceFormat::DIRECT32_DEFAULT,
+    |sdl, naive_surface, naive_renderer| {
+      naive_renderer.set_draw_color(Color::WHITE).unwrap();
+      for &(a, b) in segments.iter() {
+        naive_renderer.draw_line(a.x, a.y, b.x, b.y).unwrap();
+      }
+
+      let batch_surface = sdl
+        .create_rgb_surface(SIZE, SIZE, SurfaceFormat::DIRECT32_DEFAULT)
+        .unwrap();
+      {
+        let renderer = Renderer::from_surface(&batch_surface).unwrap();
+        renderer.set_draw_color(Color::WHITE).unwrap();
+        renderer.draw_line_batch(&segments).unwrap();
+      }
+
+      for y in 0..SIZE {
+        for x in 0..SIZE {
+          assert_eq!(
+            naive_surface.get_pixel(x, y).unwrap(),
+            batch_surface.get_pixel(x, y).unwrap(),
+            "pixel ({}, {}) differs",
+            x,
+            y
+          );
+        }
+      }
+    },
+  );
+}
@@ -0,0 +1,61 @@
+use beryllium::*;
+
+/// Some "layout code" that only knows about `DrawTarget`.
+fn draw_button(target: &dyn DrawTarget, x: i32, y: i32, pressed: bool) -> Result<(), String> {
+  target.set_draw_color(if pressed { Color::GREEN } else { Color::WHITE })?;
+  target.fill_rect(Rect { x, y, w: 20, h: 10 })?;
+  target.set_draw_color(Color::BLACK)?;
+  target.draw_lines(&[
+    Point { x, y },
+    Point { x: x + 20, y },
+    Point {
+      x: x + 20,
+      y: y + 10,
+    },
+  ])
+}
+
+#[test]
+fn test_recorder_records_in_order() {
+  let recorder = Recorder::new();
+  recorder.clear().unwrap();
+  draw_button(&recorder, 5, 5, true).unwrap();
+  recorder.draw_line(0, 0, 3, 4).unwrap();
+  assert_eq!(
+    recorder.commands(),
+    vec![
+      DrawCommand::Clear,
+      DrawCommand::SetDrawColor(Color::GREEN),
+      DrawCommand::FillRects(vec![Rect {
+        x: 5,
+        y: 5,
+        w: 20,
+        h: 10
+      }]),
+      DrawCommand::SetDrawColor(Color::BLACK),
+      DrawCommand::DrawLines(vec![
+        Point { x: 5, y: 5 },
+        Point { x: 25, y: 5 },
+        Point { x: 25, y: 15 }
+      ]),
+      DrawCommand::DrawLine {
+        x1: 0,
+        y1: 0,
+        x2: 3,
+        y2: 4
+      },
+    ]
+  );
+}
+
+#[test]
+fn test_recorder_take_and_replay() {
+  let first = Recorder::new();
+  draw_button(&first, 0, 0, false).unwrap();
+  let commands = first.take_commands();
+  assert!(first.commands().is_empty());
+
+  let second = Recorder::new();
+  Recorder::replay(&commands, &second).unwrap();
+  assert_eq!(second.take_commands(), commands);
+}
@@ -0,0 +1,87 @@
+use beryllium::*;
+
+#[rustfmt::skip]
+#[test]
+fn test_rect_inflate() {
+  let r = Rect { x: 10, y: 10, w: 20, h: 10 };
+  assert_eq!(r.inflate(2, 3), Rect { x: 8, y: 7, w: 24, h: 16 });
+  assert_eq!(r.inflate(-2, -1), Rect { x: 12, y: 11, w: 16, h: 8 });
+  // shrinking past nothing clamps to zero at the center
+  assert_eq!(r.inflate(-11, 0), Rect { x: 20, y: 10, w: 0, h: 10 });
+  assert_eq!(r.inflate(0, -6), Rect { x: 10, y: 15, w: 20, h: 0 });
+  assert_eq!(r.inflate(-50, -50), Rect { x: 20, y: 15, w: 0, h: 0 });
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_rect_offset_and_center() {
+  let r = Rect { x: 10, y: 10, w: 20, h: 10 };
+  assert_eq!(r.offset(-5, 7), Rect { x: 5, y: 17, w: 20, h: 10 });
+  assert_eq!(r.center(), Point { x: 20, y: 15 });
+  assert_eq!(Rect::centered_on(Point { x: 20, y: 15 }, 20, 10), r);
+  assert_eq!(Rect::centered_on(r.center(), r.w, r.h), r);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_rect_helpers_saturate() {
+  let max = i32::MAX;
+  let min = i32::MIN;
+  let r = Rect { x: max - 1, y: min + 1, w: max - 1, h: 4 };
+  assert_eq!(r.inflate(max, 0), Rect { x: -1, y: min + 1, w: max, h: 4 });
+  assert_eq!(r.inflate(0, min), Rect { x: max - 1, y: min + 3, w: max - 1, h: 0 });
+  assert_eq!(r.inflate(-max, max), Rect { x: max, y: min, w: 0, h: max });
+  assert_eq!(r.offset(10, -10), Rect { x: max, y: min, w: max - 1, h: 4 });
+  assert_eq!(r.center(), Point { x: max, y: min + 3 });
+  assert_eq!(Rect::centered_on(Point { x: min, y: 0 }, max, 2), Rect { x: min, y: -1, w: max, h: 2 });
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_rect_scaled() {
+  let r = Rect { x: 10, y: 10, w: 20, h: 10 };
+  assert_eq!(r.scaled(2.0), Rect { x: 20, y: 20, w: 40, h: 20 });
+  assert_eq!(r.scaled(0.25), Rect { x: 3, y: 3, w: 5, h: 3 });
+  assert_eq!(r.scaled(-1.0), Rect { x: -10, y: -10, w: 0, h: 0 });
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_rect_float_conversions() {
+  let r = Rect { x: -3, y: 4, w: 10, h: 0 };
+  assert_eq!(r.to_f(), FRect { x: -3.0, y: 4.0, w: 10.0, h: 0.0 });
+  assert_eq!(r.to_f().to_i_rounded(), r);
+  assert_eq!(r.to_f().to_i_truncated(), r);
+  let f = FRect { x: 1.5, y: -1.5, w: 2.4, h: 2.6 };
+  assert_eq!(f.to_i_rounded(), Rect { x: 2, y: -2, w: 2, h: 3 });
+  assert_eq!(f.to_i_truncated(), Rect { x: 1, y: -1, w: 2, h: 2 });
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_point_float_conversions() {
+  let p = Point { x: 7, y: -2 };
+  assert_eq!(p.to_f(), FPoint { x: 7.0, y: -2.0 });
+  assert_eq!(p.to_f().to_i_rounded(), p);
+  let f = FPoint { x: 0.5, y: -0.7 };
+  assert_eq!(f.to_i_rounded(), Point { x: 1, y: -1 });
+  assert_eq!(f.to_i_truncated(), Point { x: 0, y: 0 });
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_rect_points() {
+  let r = Rect { x: 2, y: -1, w: 3, h: 2 };
+  let points: Vec<Point> = r.points().collect();
+  assert_eq!(points, vec![
+    Point { x: 2, y: -1 }, Point { x: 3, y: -1 }, Point { x: 4, y: -1 },
+    Point { x: 2, y: 0 }, Point { x: 3, y: 0 }, Point { x: 4, y: 0 },
+  ]);
+  let mut iter = r.points();
+  assert_eq!(iter.size_hint(), (6, Some(6)));
+  iter.next();
+  assert_eq!(iter.size_hint(), (5, Some(5)));
+  assert_eq!(Rect { x: 0, y: 0, w: 0, h: 5 }.points().count(), 0);
+  assert_eq!(Rect { x: 0, y: 0, w: 5, h: -2 }.points().count(), 0);
+  assert_eq!(Rect { x: 0, y: 0, w: -3, h: -3 }.points().size_hint(), (0, Some(0)));
+}
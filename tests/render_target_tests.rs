@@ -0,0 +1,61 @@
+//! Checks that nested render target guards put each target back.
+
+use beryllium::*;
+
+mod common;
+
+#[test]
+fn test_nested_render_target_guards() {
+  common::with_surface_renderer(
+    4,
+    2,
+    SurfaceFormat::DIRECT32_DEFAULT,
+    |_sdl, surface, renderer| {
+      let outer = renderer
+        .create_texture(PixelFormatEnum::RGBA8888, TextureAccess::Target, 2, 2)
+        .unwrap();
+      let inner = renderer
+        .create_texture(PixelFormatEnum::RGBA8888, TextureAccess::Target, 2, 2)
+        .unwrap();
+
+      {
+        let mut outer_guard = renderer.push_render_target(&outer).unwrap();
+        assert!(renderer.render_target().is_some());
+        {
+          let _inner_guard = outer_guard.push(&inner).unwrap();
+          renderer.set_draw_color(Color::BLUE).unwrap();
+          renderer.clear().unwrap();
+        }
+        // drawing is back on the outer texture
+        assert!(renderer.render_target().is_some());
+        renderer.set_draw_color(Color::RED).unwrap();
+        renderer.clear().unwrap();
+      }
+      // and now back on the surface
+      assert!(renderer.render_target().is_none());
+      renderer.set_draw_color(Color::BLACK).unwrap();
+      renderer.clear().unwrap();
+
+      let left = Rect {
+        x: 0,
+        y: 0,
+        w: 2,
+        h: 2,
+      };
+      let right = Rect {
+        x: 2,
+        y: 0,
+        w: 2,
+        h: 2,
+      };
+      renderer.copy(&outer, None, Some(left)).unwrap();
+      renderer.copy(&inner, None, Some(right)).unwrap();
+      for y in 0..2 {
+        assert_eq!(surface.get_pixel(0, y).unwrap(), Color::RED);
+        assert_eq!(surface.get_pixel(1, y).unwrap(), Color::RED);
+        assert_eq!(surface.get_pixel(2, y).unwrap(), Color::BLUE);
+        assert_eq!(surface.get_pixel(3, y).unwrap(), Color::BLUE);
+      }
+    },
+  );
+}
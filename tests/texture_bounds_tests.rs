@@ -0,0 +1,122 @@
+//! Checks that texture edits outside of the texture are errors, and don't
+//! reach SDL2 (which doesn't clip them).
+
+use beryllium::*;
+
+mod common;
+
+#[test]
+fn test_texture_areas_must_be_inside() {
+  common::with_surface_renderer(
+    4,
+    4,
+    SurfaceFormat::DIRECT32_DEFAULT,
+    |_sdl, _surface, renderer| {
+      let mut texture = renderer
+        .create_texture(PixelFormatEnum::RGBA8888, TextureAccess::Streaming, 4, 4)
+        .unwrap();
+      let pixels = [0_u8; 4 * 4 * 4];
+      let outside = [
+        Rect {
+          x: -1,
+          y: 0,
+          w: 2,
+          h: 2,
+        },
+        Rect {
+          x: 0,
+          y: -1,
+          w: 2,
+          h: 2,
+        },
+        Rect {
+          x: 3,
+          y: 0,
+          w: 2,
+          h: 2,
+        },
+        Rect {
+          x: 0,
+          y: 3,
+          w: 2,
+          h: 2,
+        },
+        Rect {
+          x: 1,
+          y: 1,
+          w: -1,
+          h: 2,
+        },
+        Rect {
+          x: 1,
+          y: 1,
+          w: 2,
+          h: -1,
+        },
+      ];
+      for &rect in outside.iter() {
+        assert!(
+          texture.update(Some(rect), &pixels, 16).is_err(),
+          "{:?}",
+          rect
+        );
+      }
+      texture
+        .update(
+          Some(Rect {
+            x: 2,
+            y: 2,
+            w: 2,
+            h: 2,
+          }),
+          &pixels,
+          16,
+        )
+        .unwrap();
+      texture.update(None, &pixels, 16).unwrap();
+
+      for &rect in outside.iter() {
+        assert!(texture.lock_to_surface(Some(rect)).is_err(), "{:?}", rect);
+      }
+      let lock = texture
+        .lock_to_surface(Some(Rect {
+          x: 2,
+          y: 2,
+          w: 2,
+          h: 2,
+        }))
+        .unwrap();
+      assert_eq!(lock.surface().width(), 2);
+      assert_eq!(lock.surface().height(), 2);
+
+      let yuv = renderer
+        .create_texture(PixelFormatEnum::IYUV, TextureAccess::Streaming, 4, 4)
+        .unwrap();
+      for &rect in outside.iter() {
+        assert!(
+          yuv
+            .update_yuv(Some(rect), &pixels, 4, &pixels, 2, &pixels, 2)
+            .is_err(),
+          "{:?}",
+          rect
+        );
+      }
+      yuv
+        .update_yuv(
+          Some(Rect {
+            x: 2,
+            y: 2,
+            w: 2,
+            h: 2,
+          }),
+          &pixels,
+          4,
+          &pixels,
+          2,
+          &pixels,
+          2,
+        )
+        .unwrap();
+    },
+  );
+}
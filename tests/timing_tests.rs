@@ -0,0 +1,33 @@
+//! Checks `precise_delay` against the standard library's clock.
+
+use std::time::{Duration, Instant};
+
+mod common;
+
+#[test]
+fn test_precise_delay_is_within_tolerance() {
+  let sdl = common::init_sdl();
+  for &micros in &[0_u64, 300, 2_500, 10_000] {
+    let target = Duration::from_micros(micros);
+    let mut runs: Vec<Duration> = (0..9)
+      .map(|_| {
+        let start = Instant::now();
+        sdl.precise_delay(target);
+        let elapsed = start.elapsed();
+        // Never returning early is a hard promise, so check every run.
+        assert!(elapsed >= target, "{:?} < {:?}", elapsed, target);
+        elapsed
+      })
+      .collect();
+    runs.sort();
+    // Note: any single run can get descheduled on a busy test machine, so
+    // only the median has to be close, and even that gets a lot of slack.
+    let median = runs[runs.len() / 2];
+    assert!(
+      median < target + Duration::from_millis(10),
+      "median {:?} for {:?}",
+      median,
+      target
+    );
+  }
+}